@@ -16,7 +16,7 @@ pub mod wrap;
 pub use alias::*;
 pub use rb::AsyncRb;
 pub use traits::{consumer, producer};
-pub use transfer::async_transfer;
+pub use transfer::{async_transfer, drain_async_into_sync};
 
 #[cfg(all(test, feature = "alloc"))]
 mod tests;
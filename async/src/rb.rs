@@ -1,7 +1,7 @@
 use crate::wrap::{AsyncCons, AsyncProd};
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use core::{mem::MaybeUninit, num::NonZeroUsize, task::Waker};
 use futures::task::AtomicWaker;
 #[cfg(feature = "alloc")]
 use ringbuf::traits::Split;
@@ -19,18 +19,45 @@ impl<S: Storage, R: RbRef<Rb = AsyncRb<S>>> AsyncRbRef for R {
     type Storage = S;
 }
 
+/// Waker of a task waiting on one end of the ring buffer.
+///
+/// The ring buffer is SPSC, so only a single task is assumed to wait on each end at a time.
+/// When a waker is registered it replaces the previous one atomically.
+/// If the previous waker doesn't wake the same task (e.g. the future was moved to another task or
+/// re-polled from `select!` with a different context) then it is woken, so it can re-check the ring buffer state
+/// and register itself again instead of being lost.
+#[derive(Default)]
+pub(crate) struct WakerCell {
+    waker: AtomicWaker,
+}
+
+impl WakerCell {
+    pub(crate) fn register(&self, waker: &Waker) {
+        if let Some(old) = self.waker.take() {
+            if !old.will_wake(waker) {
+                old.wake();
+            }
+        }
+        self.waker.register(waker);
+    }
+
+    pub(crate) fn wake(&self) {
+        self.waker.wake();
+    }
+}
+
 pub struct AsyncRb<S: Storage> {
     base: SharedRb<S>,
-    pub(crate) read: AtomicWaker,
-    pub(crate) write: AtomicWaker,
+    pub(crate) read: WakerCell,
+    pub(crate) write: WakerCell,
 }
 
 impl<S: Storage> AsyncRb<S> {
     pub fn from(base: SharedRb<S>) -> Self {
         Self {
             base,
-            read: AtomicWaker::default(),
-            write: AtomicWaker::default(),
+            read: WakerCell::default(),
+            write: WakerCell::default(),
         }
     }
 }
@@ -53,6 +80,10 @@ impl<S: Storage> Observer for AsyncRb<S> {
     fn write_index(&self) -> usize {
         self.base.write_index()
     }
+    #[inline]
+    fn indices(&self) -> (usize, usize) {
+        self.base.indices()
+    }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
         self.base.unsafe_slices(start, end)
@@ -69,6 +100,21 @@ impl<S: Storage> Observer for AsyncRb<S> {
     fn write_is_held(&self) -> bool {
         self.base.write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.base.is_mirrored()
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.base.total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.base.total_read()
+    }
 }
 
 impl<S: Storage> Producer for AsyncRb<S> {
@@ -76,12 +122,20 @@ impl<S: Storage> Producer for AsyncRb<S> {
         self.base.set_write_index(value);
         self.write.wake();
     }
+    unsafe fn advance_write_index(&self, count: usize) {
+        self.base.advance_write_index(count);
+        self.write.wake();
+    }
 }
 impl<S: Storage> Consumer for AsyncRb<S> {
     unsafe fn set_read_index(&self, value: usize) {
         self.base.set_read_index(value);
         self.read.wake();
     }
+    unsafe fn advance_read_index(&self, count: usize) {
+        self.base.advance_read_index(count);
+        self.read.wake();
+    }
 }
 impl<S: Storage> RingBuffer for AsyncRb<S> {
     #[inline]
@@ -96,6 +150,10 @@ impl<S: Storage> RingBuffer for AsyncRb<S> {
         self.write.wake();
         old
     }
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.base.add_total_read(count);
+    }
 }
 
 impl<S: Storage> SplitRef for AsyncRb<S> {
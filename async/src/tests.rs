@@ -1,6 +1,6 @@
 use crate::{
     alias::{AsyncHeapCons, AsyncHeapProd, AsyncHeapRb},
-    async_transfer,
+    async_transfer, drain_async_into_sync,
     traits::*,
 };
 use alloc::vec::Vec;
@@ -181,6 +181,30 @@ fn transfer() {
     );
 }
 
+#[cfg(feature = "std")]
+#[tokio::test]
+async fn drain_into_sync() {
+    let (mut src_prod, mut src_cons) = AsyncHeapRb::<usize>::new(3).split();
+    let (mut dst_prod, mut dst_cons) = ringbuf::HeapRb::<usize>::new(COUNT).split();
+
+    let pjh = tokio::spawn(async move {
+        assert!(src_prod.push_iter_all(0..(COUNT + 6)).await);
+    });
+
+    assert_eq!(drain_async_into_sync(&mut src_cons, &mut dst_prod, Some(4)).await, 4);
+    assert!(dst_cons.pop_iter().eq(0..4));
+
+    // Stops when destination is full.
+    assert_eq!(drain_async_into_sync(&mut src_cons, &mut dst_prod, None).await, COUNT);
+    assert!(dst_cons.pop_iter().eq(4..(COUNT + 4)));
+
+    // Stops when source is closed.
+    assert_eq!(drain_async_into_sync(&mut src_cons, &mut dst_prod, None).await, 2);
+    assert!(dst_cons.pop_iter().eq((COUNT + 4)..(COUNT + 6)));
+
+    pjh.await.unwrap();
+}
+
 #[test]
 fn wait() {
     let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(3).split();
@@ -203,6 +227,38 @@ fn wait() {
     );
 }
 
+#[test]
+fn wait_occupied_below() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(4).split();
+    let stage = AtomicUsize::new(0);
+    execute!(
+        async {
+            assert!(prod.push_iter_all(0..4).await);
+            assert_eq!(stage.fetch_add(1, Ordering::SeqCst), 0);
+
+            prod.wait_occupied_below(2).await;
+            assert_eq!(stage.fetch_add(1, Ordering::SeqCst), 3);
+            assert!(prod.occupied_len() < 2);
+
+            drop(prod);
+        },
+        async {
+            cons.wait_occupied(4).await;
+            assert_eq!(cons.pop().await, Some(0));
+            assert_eq!(cons.pop().await, Some(1));
+            assert_eq!(stage.fetch_add(1, Ordering::SeqCst), 1);
+
+            // Producer is still paused because there are two items left.
+            futures::pending!();
+            assert_eq!(stage.fetch_add(1, Ordering::SeqCst), 2);
+            assert_eq!(cons.pop().await, Some(2));
+
+            assert_eq!(cons.pop().await, Some(3));
+            assert_eq!(cons.pop().await, None);
+        },
+    );
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn drop_close_prod() {
@@ -252,3 +308,102 @@ fn drop_close_cons() {
     t0.join().unwrap();
     t1.join().unwrap();
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn waker_cell_replace() {
+    use crate::rb::WakerCell;
+    use futures::task::{waker, ArcWake};
+    use std::sync::atomic::AtomicBool;
+
+    #[derive(Default)]
+    struct Flag(AtomicBool);
+    impl ArcWake for Flag {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let cell = WakerCell::default();
+    let (first, second) = (Arc::new(Flag::default()), Arc::new(Flag::default()));
+
+    cell.register(&waker(first.clone()));
+    cell.register(&waker(first.clone()));
+    assert!(!first.0.load(Ordering::SeqCst));
+
+    // Replaced waker must not be lost.
+    cell.register(&waker(second.clone()));
+    assert!(first.0.load(Ordering::SeqCst));
+    assert!(!second.0.load(Ordering::SeqCst));
+
+    cell.wake();
+    assert!(second.0.load(Ordering::SeqCst));
+}
+
+#[test]
+fn select_reregister() {
+    use futures::{channel::mpsc, select, SinkExt, StreamExt};
+
+    let (prod, cons) = AsyncHeapRb::<usize>::new(2).split();
+    let (tick_tx, tick_rx) = mpsc::channel::<()>(1);
+    execute!(
+        async move {
+            let mut prod = prod;
+            let mut tick_tx = tick_tx;
+            for i in 0..COUNT {
+                prod.push(i).await.unwrap();
+                let _ = tick_tx.send(()).await;
+            }
+        },
+        async move {
+            let mut cons = cons;
+            let mut tick_rx = tick_rx.fuse();
+            let mut i = 0;
+            while i < COUNT {
+                // Each iteration creates a new pop future and registers the waker again.
+                select! {
+                    item = cons.pop() => {
+                        assert_eq!(item, Some(i));
+                        i += 1;
+                    },
+                    _ = tick_rx.next() => {},
+                }
+            }
+        },
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio_read_write() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let (mut prod, mut cons) = AsyncHeapRb::<u8>::new(7).split();
+    let input = (0..=255).cycle().take(COUNT).collect::<Vec<u8>>();
+
+    let pjh = tokio::spawn({
+        let input = input.clone();
+        async move {
+            prod.write_all(&input).await.unwrap();
+            prod.shutdown().await.unwrap();
+        }
+    });
+
+    let mut output = Vec::new();
+    assert_eq!(cons.read_to_end(&mut output).await.unwrap(), COUNT);
+    assert_eq!(output, input);
+    pjh.await.unwrap();
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio_eof_on_drop() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let (mut prod, mut cons) = AsyncHeapRb::<u8>::new(4).split();
+    prod.write_all(&[1, 2, 3]).await.unwrap();
+    drop(prod);
+
+    let mut buf = [0; 4];
+    assert_eq!(cons.read(&mut buf).await.unwrap(), 3);
+    assert_eq!(buf[..3], [1, 2, 3]);
+    assert_eq!(cons.read(&mut buf).await.unwrap(), 0);
+}
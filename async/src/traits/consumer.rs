@@ -9,6 +9,9 @@ use ringbuf::traits::Consumer;
 use std::io;
 
 pub trait AsyncConsumer: Consumer {
+    /// Register waker to be woken on the next producer activity.
+    ///
+    /// Only the most recently registered waker is stored, so only a single task may wait at a time.
     fn register_waker(&self, waker: &Waker);
 
     fn close(&mut self);
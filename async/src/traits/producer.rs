@@ -10,6 +10,9 @@ use ringbuf::traits::Producer;
 use std::io;
 
 pub trait AsyncProducer: Producer {
+    /// Register waker to be woken on the next consumer activity.
+    ///
+    /// Only the most recently registered waker is stored, so only a single task may wait at a time.
     fn register_waker(&self, waker: &Waker);
 
     fn close(&mut self);
@@ -56,6 +59,23 @@ pub trait AsyncProducer: Producer {
         }
     }
 
+    /// Wait for the number of items in the buffer to become less than `threshold` or for the buffer to close.
+    ///
+    /// Useful for flow control, e.g. to resume paused upstream when the consumer has drained enough items.
+    ///
+    /// `threshold` must be greater than zero, otherwise the condition is never met and the future resolves only when the buffer is closed.
+    /// In debug mode panics if `threshold` is zero.
+    ///
+    /// The method takes `&mut self` because only single [`WaitOccupiedBelowFuture`] is allowed at a time.
+    fn wait_occupied_below(&mut self, threshold: usize) -> WaitOccupiedBelowFuture<'_, Self> {
+        debug_assert!(threshold > 0);
+        WaitOccupiedBelowFuture {
+            owner: self,
+            threshold,
+            done: false,
+        }
+    }
+
     /// Copy slice contents to the buffer waiting asynchronously if the buffer is full.
     ///
     /// Future returns:
@@ -256,3 +276,38 @@ impl<'a, A: AsyncProducer> Future for WaitVacantFuture<'a, A> {
         }
     }
 }
+
+/// Future returned by [`AsyncProducer::wait_occupied_below`].
+///
+/// Resolves when the number of items in the buffer is less than threshold or when the buffer is closed.
+pub struct WaitOccupiedBelowFuture<'a, A: AsyncProducer + ?Sized> {
+    owner: &'a A,
+    threshold: usize,
+    done: bool,
+}
+impl<'a, A: AsyncProducer> Unpin for WaitOccupiedBelowFuture<'a, A> {}
+impl<'a, A: AsyncProducer> FusedFuture for WaitOccupiedBelowFuture<'a, A> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+impl<'a, A: AsyncProducer> Future for WaitOccupiedBelowFuture<'a, A> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            assert!(!self.done);
+            let closed = self.owner.is_closed();
+            if self.owner.occupied_len() < self.threshold || closed {
+                self.done = true;
+                break Poll::Ready(());
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
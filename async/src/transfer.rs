@@ -1,4 +1,5 @@
 use crate::{consumer::AsyncConsumer, producer::AsyncProducer};
+use ringbuf::{traits::Producer, transfer};
 
 /// Tranfer data from one ring buffer to another.
 ///
@@ -18,7 +19,7 @@ pub async fn async_transfer<T, As: AsyncConsumer<Item = T>, Ad: AsyncProducer<It
     let mut actual_count = 0;
     // TODO: Transfer multiple items at once.
     loop {
-        if count.as_ref().map_or(false, |n| actual_count == *n) {
+        if count.as_ref().is_some_and(|n| actual_count == *n) {
             break;
         }
         actual_count += 1;
@@ -36,3 +37,43 @@ pub async fn async_transfer<T, As: AsyncConsumer<Item = T>, Ad: AsyncProducer<It
     }
     actual_count
 }
+
+/// Transfer data from async consumer to sync producer.
+///
+/// `count` is the number of items to transfer.
+/// The number of actually transfered items is returned.
+///
+/// Items available in `src` are moved at once, otherwise transfer waits for the next item asynchronously.
+///
+/// Transfer stops when `count` items are moved (if `count` is `Some`), when `src` is closed and empty,
+/// or when `dst` is full because sync producer cannot wait for vacant space.
+pub async fn drain_async_into_sync<T, As: AsyncConsumer<Item = T>, Pd: Producer<Item = T>>(
+    src: &mut As,
+    dst: &mut Pd,
+    count: Option<usize>,
+) -> usize {
+    let mut actual_count = 0;
+    loop {
+        let remaining = count.map(|n| n - actual_count);
+        if remaining == Some(0) || dst.is_full() {
+            break;
+        }
+
+        let n = transfer(src, dst, remaining);
+        if n > 0 {
+            actual_count += n;
+            continue;
+        }
+
+        match src.pop().await {
+            Some(item) => {
+                if dst.try_push(item).is_err() {
+                    unreachable!("Destination must have vacant space");
+                }
+                actual_count += 1;
+            }
+            None => break,
+        }
+    }
+    actual_count
+}
@@ -72,3 +72,31 @@ where
         }
     }
 }
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncRbRef> tokio::io::AsyncRead for AsyncCons<R>
+where
+    Self: AsyncConsumer<Item = u8>,
+{
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let mut waker_registered = false;
+        loop {
+            let closed = self.is_closed();
+            // Items are copied directly into the unfilled part of the buffer, so it isn't zeroed first.
+            // Buffer memory is only written with initialized items, so it's safe to access it as uninit.
+            let len = self.pop_slice_uninit(unsafe { buf.unfilled_mut() });
+            if len != 0 || closed {
+                // `len` items were written to the beginning of the unfilled part.
+                unsafe { buf.assume_init(len) };
+                buf.advance(len);
+                // Closed producer results in zero-length read which is EOF in terms of `tokio`.
+                break Poll::Ready(Ok(()));
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
@@ -72,3 +72,21 @@ where
         Poll::Ready(Ok(()))
     }
 }
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncRbRef> tokio::io::AsyncWrite for AsyncProd<R>
+where
+    R::Rb: RingBuffer<Item = u8>,
+{
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        <Self as AsyncProducer>::poll_write(self, cx, buf)
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Don't need to be flushed.
+        Poll::Ready(Ok(()))
+    }
+    fn poll_shutdown(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.close();
+        Poll::Ready(Ok(()))
+    }
+}
@@ -3,7 +3,11 @@ use crate::sync::StdSemaphore;
 use crate::{sync::Semaphore, BlockingCons, BlockingProd};
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use core::{
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
 #[cfg(feature = "alloc")]
 use ringbuf::traits::Split;
 use ringbuf::{
@@ -18,12 +22,20 @@ pub struct BlockingRb<S: Storage, X: Semaphore> {
     base: SharedRb<S>,
     pub(crate) read: X,
     pub(crate) write: X,
+    /// Given on any change while there are waiting observers.
+    pub(crate) observe: X,
+    /// Number of observers waiting on [`Self::observe`].
+    pub(crate) observers: AtomicUsize,
 }
 #[cfg(feature = "std")]
 pub struct BlockingRb<S: Storage, X: Semaphore = StdSemaphore> {
     base: SharedRb<S>,
     pub(crate) read: X,
     pub(crate) write: X,
+    /// Given on any change while there are waiting observers.
+    pub(crate) observe: X,
+    /// Number of observers waiting on [`Self::observe`].
+    pub(crate) observers: AtomicUsize,
 }
 
 impl<S: Storage, X: Semaphore> BlockingRb<S, X> {
@@ -32,6 +44,19 @@ impl<S: Storage, X: Semaphore> BlockingRb<S, X> {
             base,
             read: X::default(),
             write: X::default(),
+            observe: X::default(),
+            observers: AtomicUsize::new(0),
+        }
+    }
+
+    /// Wake up waiting observer if there is any.
+    ///
+    /// Must be called after the change is made.
+    fn notify_observers(&self) {
+        // Pairs with the fence in `BlockingObs` waiting: either the observer sees the change or it is counted here.
+        fence(Ordering::SeqCst);
+        if self.observers.load(Ordering::Relaxed) != 0 {
+            self.observe.give();
         }
     }
 }
@@ -52,6 +77,10 @@ impl<S: Storage, X: Semaphore> Observer for BlockingRb<S, X> {
     fn write_index(&self) -> usize {
         self.base.write_index()
     }
+    #[inline]
+    fn indices(&self) -> (usize, usize) {
+        self.base.indices()
+    }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
         self.base.unsafe_slices(start, end)
@@ -68,30 +97,62 @@ impl<S: Storage, X: Semaphore> Observer for BlockingRb<S, X> {
     fn write_is_held(&self) -> bool {
         self.base.write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.base.is_mirrored()
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.base.total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.base.total_read()
+    }
 }
 impl<S: Storage, X: Semaphore> Producer for BlockingRb<S, X> {
     unsafe fn set_write_index(&self, value: usize) {
         self.base.set_write_index(value);
         self.write.give();
+        self.notify_observers();
+    }
+    unsafe fn advance_write_index(&self, count: usize) {
+        self.base.advance_write_index(count);
+        self.write.give();
+        self.notify_observers();
     }
 }
 impl<S: Storage, X: Semaphore> Consumer for BlockingRb<S, X> {
     unsafe fn set_read_index(&self, value: usize) {
         self.base.set_read_index(value);
         self.read.give();
+        self.notify_observers();
+    }
+    unsafe fn advance_read_index(&self, count: usize) {
+        self.base.advance_read_index(count);
+        self.read.give();
+        self.notify_observers();
     }
 }
 impl<S: Storage, X: Semaphore> RingBuffer for BlockingRb<S, X> {
     unsafe fn hold_read(&self, flag: bool) -> bool {
         let old = self.base.hold_read(flag);
         self.read.give();
+        self.notify_observers();
         old
     }
     unsafe fn hold_write(&self, flag: bool) -> bool {
         let old = self.base.hold_write(flag);
         self.write.give();
+        self.notify_observers();
         old
     }
+    fn add_total_read(&self, count: usize) {
+        self.base.add_total_read(count);
+    }
 }
 
 impl<S: Storage, X: Semaphore> SplitRef for BlockingRb<S, X> {
@@ -1,4 +1,9 @@
-use core::time::Duration;
+use core::{
+    hint::spin_loop,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 #[cfg(feature = "std")]
 use std::{
     mem::replace,
@@ -37,7 +42,7 @@ pub trait Semaphore: Default {
     /// + on timeout - `false`.
     fn take(&self, timeout: Option<Duration>) -> bool;
 
-    fn take_iter(&self, timeout: Option<Duration>) -> TakeIter<Self> {
+    fn take_iter(&self, timeout: Option<Duration>) -> TakeIter<'_, Self> {
         TakeIter {
             reset: false,
             semaphore: self,
@@ -100,6 +105,47 @@ impl Semaphore for StdSemaphore {
     }
 }
 
+/// Spin-based binary semaphore.
+///
+/// Doesn't depend on `std`, so it can be used on `no_std` multi-core targets.
+/// Waiting is done by busy-looping with [`spin_loop`] hint.
+///
+/// Timeout is measured using `I`, e.g. an [`Instant`] implementation based on hardware cycle counter.
+pub struct SpinSemaphore<I: Instant> {
+    flag: AtomicBool,
+    _ghost: PhantomData<fn() -> I>,
+}
+
+impl<I: Instant> Default for SpinSemaphore<I> {
+    fn default() -> Self {
+        Self {
+            flag: AtomicBool::new(false),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<I: Instant> Semaphore for SpinSemaphore<I> {
+    type Instant = I;
+
+    fn give(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+
+    fn try_take(&self) -> bool {
+        self.flag.swap(false, Ordering::Acquire)
+    }
+    fn take(&self, timeout: Option<Duration>) -> bool {
+        for _ in TimeoutIter::<Self::Instant>::new(timeout) {
+            if self.try_take() {
+                return true;
+            }
+            spin_loop();
+        }
+        self.try_take()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TimeoutIter<I: Instant> {
     start: I,
@@ -1,5 +1,11 @@
-use crate::{traits::*, wrap::WaitError, BlockingHeapRb};
+use crate::{
+    sync::{Instant, Semaphore, SpinSemaphore, StdInstant},
+    traits::*,
+    wrap::{WaitError, WaitStrategy},
+    BlockingHeapRb,
+};
 use std::{
+    cell::Cell,
     io::{Read, Write},
     sync::Arc,
     thread,
@@ -194,3 +200,185 @@ fn write_read() {
 
     assert_eq!(*smsg, rmsg);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn spin_wait() {
+    let rb = BlockingHeapRb::<u8, SpinSemaphore<StdInstant>>::new(7);
+    let (mut prod, mut cons) = rb.split();
+    cons.set_timeout(TIMEOUT);
+
+    let pjh = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        prod.try_push(123).unwrap();
+    });
+
+    assert_eq!(cons.wait_occupied(1), Ok(()));
+    assert_eq!(cons.try_pop(), Some(123));
+    pjh.join().unwrap();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn spin_strategy() {
+    let rb = BlockingHeapRb::<u8>::new(7);
+    let (mut prod, mut cons) = rb.split();
+    prod.set_wait_strategy(WaitStrategy::SpinThenPark { spin_iters: 100 });
+    cons.set_wait_strategy(WaitStrategy::Spin { iters: 100 });
+    cons.set_timeout(TIMEOUT);
+
+    let pjh = thread::spawn(move || {
+        for i in 0..100 {
+            assert_eq!(prod.wait_vacant(1), Ok(()));
+            prod.try_push(i).unwrap();
+        }
+    });
+
+    for i in 0..100 {
+        assert_eq!(cons.wait_occupied(1), Ok(()));
+        assert_eq!(cons.try_pop(), Some(i));
+    }
+    pjh.join().unwrap();
+
+    let start = StdInstant::now();
+    assert_eq!(cons.wait_occupied(1), Err(WaitError::Closed));
+    assert!(start.elapsed() < TIMEOUT.unwrap());
+}
+
+/// Instant that counts calls to [`Instant::elapsed`] like a cycle counter.
+struct CountingInstant;
+
+std::thread_local! {
+    static CYCLES: Cell<u64> = const { Cell::new(0) };
+}
+
+impl Instant for CountingInstant {
+    fn now() -> Self {
+        CYCLES.set(0);
+        Self
+    }
+    fn elapsed(&self) -> Duration {
+        let cycles = CYCLES.get() + 1;
+        CYCLES.set(cycles);
+        Duration::from_nanos(cycles)
+    }
+}
+
+#[test]
+fn spin_timeout() {
+    let sem = SpinSemaphore::<CountingInstant>::default();
+    assert!(!sem.take(Some(Duration::from_nanos(100))));
+    assert_eq!(CYCLES.get(), 100);
+
+    sem.give();
+    assert!(sem.take(Some(Duration::from_nanos(100))));
+    assert_eq!(CYCLES.get(), 1);
+    assert!(!sem.try_take());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn observe_wait() {
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, cons) = rb.split();
+    let mut obs = prod.observe_blocking();
+    obs.set_timeout(TIMEOUT);
+
+    let ojh = thread::spawn(move || {
+        assert_eq!(obs.wait_occupied(3), Ok(()));
+        assert!(obs.occupied_len() >= 3);
+        obs
+    });
+
+    for i in 0..3 {
+        thread::sleep(Duration::from_millis(10));
+        prod.try_push(i).unwrap();
+    }
+    let obs = ojh.join().unwrap();
+
+    assert_eq!(obs.wait_vacant(1), Ok(()));
+    drop(cons);
+    prod.push_slice(&[3]);
+    assert_eq!(obs.wait_vacant(1), Err(WaitError::Closed));
+    drop(prod);
+    assert_eq!(obs.wait_occupied(4), Ok(()));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn push_timeout() {
+    let rb = BlockingHeapRb::<u8>::new(2);
+    let (mut prod, _cons) = rb.split();
+    prod.set_timeout(None);
+
+    assert_eq!(prod.try_push_timeout(0, Duration::from_millis(10)), Ok(()));
+    assert_eq!(prod.try_push_timeout(1, Duration::from_millis(10)), Ok(()));
+    assert_eq!(prod.try_push_timeout(2, Duration::from_millis(10)), Err((2, WaitError::TimedOut)));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn pop_timeout() {
+    let rb = BlockingHeapRb::<u8>::new(2);
+    let (mut prod, mut cons) = rb.split();
+    cons.set_timeout(None);
+
+    assert_eq!(cons.try_pop_timeout(Duration::from_millis(10)), Err(WaitError::TimedOut));
+
+    let pjh = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        prod.try_push(123).unwrap();
+    });
+    assert_eq!(cons.try_pop_timeout(Duration::from_millis(1000)), Ok(Some(123)));
+
+    pjh.join().unwrap();
+    assert_eq!(cons.try_pop_timeout(Duration::from_millis(10)), Ok(None));
+}
+
+#[cfg(feature = "futures")]
+fn sink_stream<E: FnMut(crate::wrap::adapt::Job) + Send + Copy + 'static>(spawn: E) {
+    use futures::{executor::block_on, stream, SinkExt, StreamExt};
+
+    let rb = BlockingHeapRb::<usize>::new(3);
+    let (prod, cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        let mut sink = prod.into_sink(spawn);
+        block_on(sink.send_all(&mut stream::iter(0..64).map(Ok))).unwrap();
+        block_on(sink.close()).unwrap();
+    });
+
+    let items = block_on(cons.into_stream(spawn).collect::<Vec<_>>());
+    pjh.join().unwrap();
+    assert!(items.into_iter().eq(0..64));
+}
+
+#[cfg(feature = "futures")]
+#[test]
+#[cfg_attr(miri, ignore)]
+fn sink_stream_inline() {
+    sink_stream(|job| job());
+}
+
+#[cfg(feature = "futures")]
+#[test]
+#[cfg_attr(miri, ignore)]
+fn sink_stream_thread() {
+    sink_stream(|job| {
+        thread::spawn(job);
+    });
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn sink_closed() {
+    use futures::{executor::block_on, SinkExt};
+
+    let rb = BlockingHeapRb::<u8>::new(1);
+    let (prod, cons) = rb.split();
+    let mut sink = prod.into_sink(|job| job());
+
+    assert_eq!(block_on(sink.send(0)), Ok(()));
+    drop(cons);
+    assert_eq!(block_on(sink.send(1)), Err(WaitError::Closed));
+}
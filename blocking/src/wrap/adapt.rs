@@ -0,0 +1,190 @@
+//! Adapters of blocking producer and consumer to [`Sink`] and [`Stream`].
+//!
+//! Blocking waits are not performed inside `poll_*` methods.
+//! Instead, producer or consumer is moved into a job which is passed to user-provided `spawn` hook
+//! (e.g. `tokio::task::spawn_blocking` or [`std::thread::spawn`]) and is returned back when the job is done.
+
+use super::{ready_or_closed, BlockingCons, BlockingProd, WaitError};
+use crate::rb::BlockingRbRef;
+use alloc::boxed::Box;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{channel::oneshot, ready, FutureExt, Sink, Stream};
+use ringbuf::traits::{Consumer, Observer, Producer};
+
+/// Blocking job passed to a spawn hook.
+pub type Job = Box<dyn FnOnce() + Send + 'static>;
+
+enum State<W> {
+    Idle(W),
+    Waiting(oneshot::Receiver<(W, Result<(), WaitError>)>),
+    Closed,
+}
+
+impl<W: Send + 'static> State<W> {
+    fn spawn<E, F>(&mut self, spawn: &mut E, wait: F)
+    where
+        E: FnMut(Job),
+        F: FnOnce(&mut W) -> Result<(), WaitError> + Send + 'static,
+    {
+        let mut this = match core::mem::replace(self, State::Closed) {
+            State::Idle(this) => this,
+            _ => unreachable!(),
+        };
+        let (tx, rx) = oneshot::channel();
+        *self = State::Waiting(rx);
+        spawn(Box::new(move || {
+            let res = wait(&mut this);
+            let _ = tx.send((this, res));
+        }));
+    }
+
+    /// Wait for the job to finish and return its result.
+    fn poll_idle(&mut self, cx: &mut Context<'_>) -> Poll<Result<&mut W, WaitError>> {
+        if let State::Waiting(rx) = self {
+            match ready!(rx.poll_unpin(cx)) {
+                Ok((this, res)) => {
+                    *self = State::Idle(this);
+                    res?;
+                }
+                // Job was dropped without being run.
+                Err(oneshot::Canceled) => {
+                    *self = State::Closed;
+                }
+            }
+        }
+        Poll::Ready(match self {
+            State::Idle(this) => Ok(this),
+            State::Closed => Err(WaitError::Closed),
+            State::Waiting(..) => unreachable!(),
+        })
+    }
+}
+
+/// [`Sink`] that pushes items into [`BlockingProd`].
+///
+/// Created by [`BlockingProd::into_sink`].
+pub struct BlockingSink<R: BlockingRbRef, E: FnMut(Job)> {
+    state: State<BlockingProd<R>>,
+    spawn: E,
+}
+
+/// [`Stream`] that pops items from [`BlockingCons`].
+///
+/// Created by [`BlockingCons::into_stream`].
+pub struct BlockingStream<R: BlockingRbRef, E: FnMut(Job)> {
+    state: State<BlockingCons<R>>,
+    spawn: E,
+}
+
+impl<R: BlockingRbRef> BlockingProd<R> {
+    /// Convert into [`Sink`] which offloads waiting for vacant space to jobs passed to `spawn` hook.
+    pub fn into_sink<E: FnMut(Job)>(self, spawn: E) -> BlockingSink<R, E> {
+        BlockingSink {
+            state: State::Idle(self),
+            spawn,
+        }
+    }
+}
+
+impl<R: BlockingRbRef> BlockingCons<R> {
+    /// Convert into [`Stream`] which offloads waiting for items to jobs passed to `spawn` hook.
+    pub fn into_stream<E: FnMut(Job)>(self, spawn: E) -> BlockingStream<R, E> {
+        BlockingStream {
+            state: State::Idle(self),
+            spawn,
+        }
+    }
+}
+
+impl<R: BlockingRbRef, E: FnMut(Job)> BlockingSink<R, E> {
+    /// Get back the producer.
+    ///
+    /// Returns `None` if the sink is closed or waiting job is in progress.
+    pub fn into_inner(self) -> Option<BlockingProd<R>> {
+        match self.state {
+            State::Idle(prod) => Some(prod),
+            _ => None,
+        }
+    }
+}
+
+impl<R: BlockingRbRef, E: FnMut(Job)> BlockingStream<R, E> {
+    /// Get back the consumer.
+    ///
+    /// Returns `None` if the stream is closed or waiting job is in progress.
+    pub fn into_inner(self) -> Option<BlockingCons<R>> {
+        match self.state {
+            State::Idle(cons) => Some(cons),
+            _ => None,
+        }
+    }
+}
+
+impl<R: BlockingRbRef, E: FnMut(Job)> Unpin for BlockingSink<R, E> {}
+impl<R: BlockingRbRef, E: FnMut(Job)> Unpin for BlockingStream<R, E> {}
+
+impl<R: BlockingRbRef + Send + 'static, E: FnMut(Job)> Sink<<R::Rb as Observer>::Item> for BlockingSink<R, E> {
+    type Error = WaitError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = &mut *self;
+        loop {
+            let prod = ready!(this.state.poll_idle(cx))?;
+            if !prod.is_full() {
+                return Poll::Ready(Ok(()));
+            }
+            if prod.is_closed() {
+                return Poll::Ready(Err(WaitError::Closed));
+            }
+            this.state.spawn(&mut this.spawn, |prod| prod.wait_vacant(1));
+        }
+    }
+    fn start_send(mut self: Pin<&mut Self>, item: <R::Rb as Observer>::Item) -> Result<(), Self::Error> {
+        match &mut self.state {
+            State::Idle(prod) => {
+                assert!(prod.try_push(item).is_ok(), "`poll_ready` must be called before `start_send`");
+                Ok(())
+            }
+            State::Waiting(..) => panic!("`poll_ready` must be called before `start_send`"),
+            State::Closed => Err(WaitError::Closed),
+        }
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Items are already in the ring buffer.
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let _ = ready!(self.state.poll_idle(cx));
+        // Dropping the producer closes the ring buffer.
+        self.state = State::Closed;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R: BlockingRbRef + Send + 'static, E: FnMut(Job)> Stream for BlockingStream<R, E> {
+    type Item = <R::Rb as Observer>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            let cons = match ready!(this.state.poll_idle(cx)) {
+                Ok(cons) => cons,
+                Err(WaitError::Closed) => return Poll::Ready(None),
+                // Timeout is not an end of stream, so just keep waiting.
+                Err(WaitError::TimedOut) => continue,
+            };
+            match ready_or_closed(cons, |cons| cons.is_closed(), |cons| cons.try_pop()) {
+                Some(Ok(item)) => return Poll::Ready(Some(item)),
+                Some(Err(_)) => {
+                    this.state = State::Closed;
+                    return Poll::Ready(None);
+                }
+                None => (),
+            }
+            this.state.spawn(&mut this.spawn, |cons| cons.wait_occupied(1));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{BlockingWrap, WaitError};
+use super::{ready_or_closed, BlockingWrap, WaitError};
 use crate::{rb::BlockingRbRef, sync::Semaphore};
 use core::time::Duration;
 #[cfg(feature = "std")]
@@ -17,7 +17,10 @@ impl<R: BlockingRbRef> DelegateConsumer for BlockingCons<R> {}
 
 macro_rules! wait_iter {
     ($self:expr) => {
-        $self.rb.rb().write.take_iter($self.timeout()).reset()
+        wait_iter!($self, $self.timeout())
+    };
+    ($self:expr, $timeout:expr) => {
+        $self.rb.rb().write.take_iter($timeout).reset()
     };
 }
 
@@ -35,12 +38,19 @@ impl<R: BlockingRbRef> BlockingCons<R> {
 
     pub fn wait_occupied(&mut self, count: usize) -> Result<(), WaitError> {
         debug_assert!(count <= self.rb().capacity().get());
+        let ready = |this: &Self| {
+            ready_or_closed(
+                this,
+                |this| this.is_closed(),
+                |this| (this.base.occupied_len() >= count).then_some(()),
+            )
+        };
+        if let Some(res) = self.spin_wait(ready) {
+            return res;
+        }
         for _ in wait_iter!(self) {
-            if self.base.occupied_len() >= count {
-                return Ok(());
-            }
-            if self.is_closed() {
-                return Err(WaitError::Closed);
+            if let Some(res) = ready(self) {
+                return res;
             }
         }
         Err(WaitError::TimedOut)
@@ -48,11 +58,20 @@ impl<R: BlockingRbRef> BlockingCons<R> {
 
     pub fn pop(&mut self) -> Result<<Self as Observer>::Item, WaitError> {
         for _ in wait_iter!(self) {
-            if let Some(item) = self.base.try_pop() {
-                return Ok(item);
+            if let Some(res) = ready_or_closed(&mut self.base, |base| !base.write_is_held(), |base| base.try_pop()) {
+                return res;
             }
-            if self.is_closed() {
-                return Err(WaitError::Closed);
+        }
+        Err(WaitError::TimedOut)
+    }
+
+    /// Pop single item waiting at most `timeout` for it regardless of [`Self::timeout`].
+    ///
+    /// Returns `Ok(None)` if the ring buffer is empty and producer is closed.
+    pub fn try_pop_timeout(&mut self, timeout: Duration) -> Result<Option<<Self as Observer>::Item>, WaitError> {
+        for _ in wait_iter!(self, Some(timeout)) {
+            if let Some(res) = ready_or_closed(&mut self.base, |base| !base.write_is_held(), |base| base.try_pop()) {
+                return Ok(res.ok());
             }
         }
         Err(WaitError::TimedOut)
@@ -1,8 +1,14 @@
+#[cfg(feature = "futures")]
+pub mod adapt;
 mod cons;
+mod obs;
 mod prod;
 
-use crate::rb::BlockingRbRef;
-use core::time::Duration;
+use crate::{
+    rb::BlockingRbRef,
+    sync::{Semaphore, TimeoutIter},
+};
+use core::{hint::spin_loop, time::Duration};
 use ringbuf::{
     traits::Based,
     wrap::{caching::Caching, Wrap},
@@ -13,6 +19,23 @@ pub struct BlockingWrap<R: BlockingRbRef, const P: bool, const C: bool> {
     pub(crate) rb: R,
     pub(crate) base: Caching<R, P, C>,
     pub(crate) timeout: Option<Duration>,
+    pub(crate) wait_strategy: WaitStrategy,
+}
+
+/// Strategy of waiting for vacant space or items in [`BlockingProd::wait_vacant`] and [`BlockingCons::wait_occupied`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum WaitStrategy {
+    /// Park the thread on the semaphore until the other side notifies it.
+    #[default]
+    Park,
+    /// Busy-wait until the condition is met, the other side is closed or the timeout is elapsed, never park.
+    ///
+    /// `iters` is the number of checks between timeout checks.
+    Spin { iters: usize },
+    /// Busy-wait for at most `spin_iters` checks and then park.
+    ///
+    /// Time spent spinning is not counted in timeout.
+    SpinThenPark { spin_iters: usize },
 }
 
 impl<R: BlockingRbRef, const P: bool, const C: bool> BlockingWrap<R, P, C> {
@@ -21,12 +44,54 @@ impl<R: BlockingRbRef, const P: bool, const C: bool> BlockingWrap<R, P, C> {
             rb: rb.clone(),
             base: Caching::new(rb),
             timeout: None,
+            wait_strategy: WaitStrategy::default(),
+        }
+    }
+
+    pub fn set_wait_strategy(&mut self, wait_strategy: WaitStrategy) {
+        self.wait_strategy = wait_strategy;
+    }
+    pub fn wait_strategy(&self) -> WaitStrategy {
+        self.wait_strategy
+    }
+
+    /// Spin-check `ready` according to wait strategy.
+    ///
+    /// Returns `None` if waiting should continue by parking.
+    pub(crate) fn spin_wait<F: FnMut(&Self) -> Option<Result<(), WaitError>>>(&self, mut ready: F) -> Option<Result<(), WaitError>> {
+        match self.wait_strategy {
+            WaitStrategy::Park => None,
+            WaitStrategy::Spin { iters } => {
+                for _ in TimeoutIter::<<R::Semaphore as Semaphore>::Instant>::new(self.timeout) {
+                    for _ in 0..iters.max(1) {
+                        if let Some(res) = ready(self) {
+                            return Some(res);
+                        }
+                        spin_loop();
+                    }
+                }
+                Some(ready(self).unwrap_or(Err(WaitError::TimedOut)))
+            }
+            WaitStrategy::SpinThenPark { spin_iters } => {
+                for _ in 0..spin_iters {
+                    if let Some(res) = ready(self) {
+                        return Some(res);
+                    }
+                    spin_loop();
+                }
+                None
+            }
         }
     }
 
     pub fn observe(&self) -> Obs<R> {
         self.base().observe()
     }
+
+    /// Get blocking observer that can wait for occupancy changes.
+    pub fn observe_blocking(&self) -> BlockingObs<R> {
+        BlockingObs::new(self.rb.clone())
+    }
 }
 impl<R: BlockingRbRef, const P: bool, const C: bool> Based for BlockingWrap<R, P, C> {
     type Base = Caching<R, P, C>;
@@ -64,5 +129,27 @@ pub enum WaitError {
     Closed,
 }
 
+/// Check whether the other side of `this` is `closed` and try to `take` a value.
+///
+/// Closing is checked first, so that changes made by the other side before closing are not missed:
+/// if `take` fails after the other side is closed then it will never succeed.
+///
+/// Returns `None` if `take` failed but the other side is not closed, so waiting should continue.
+pub(crate) fn ready_or_closed<X, T>(
+    this: X,
+    closed: impl FnOnce(&X) -> bool,
+    take: impl FnOnce(X) -> Option<T>,
+) -> Option<Result<T, WaitError>> {
+    let closed = closed(&this);
+    match take(this) {
+        Some(value) => Some(Ok(value)),
+        None if closed => Some(Err(WaitError::Closed)),
+        None => None,
+    }
+}
+
+#[cfg(feature = "futures")]
+pub use adapt::{BlockingSink, BlockingStream};
 pub use cons::*;
+pub use obs::*;
 pub use prod::*;
@@ -0,0 +1,113 @@
+use super::{ready_or_closed, WaitError};
+use crate::{rb::BlockingRbRef, sync::Semaphore};
+use core::{
+    sync::atomic::{fence, AtomicUsize, Ordering},
+    time::Duration,
+};
+use ringbuf::{
+    traits::{observer::DelegateObserver, Based, Observer},
+    wrap::Wrap,
+    Obs,
+};
+
+/// Blocking observer of a ring buffer.
+///
+/// Can wait for occupancy changes without holding producer or consumer.
+///
+/// Waiting is notified by both producer and consumer, but only one observer should wait at a time,
+/// otherwise observers may steal wake-ups from each other and wake up only by timeout.
+/// That's why the observer cannot be cloned.
+///
+/// Producer and consumer notify observers only while some of them are waiting, so there is no overhead otherwise.
+pub struct BlockingObs<R: BlockingRbRef> {
+    base: Obs<R>,
+    timeout: Option<Duration>,
+}
+
+impl<R: BlockingRbRef> DelegateObserver for BlockingObs<R> {}
+
+macro_rules! wait_iter {
+    ($self:expr) => {
+        $self.base.rb().observe.take_iter($self.timeout()).reset()
+    };
+}
+
+/// Counts observer as waiting while alive.
+struct Waiting<'a>(&'a AtomicUsize);
+
+impl<'a> Waiting<'a> {
+    fn new(observers: &'a AtomicUsize) -> Self {
+        observers.fetch_add(1, Ordering::Relaxed);
+        // Pairs with the fence in ring buffer notification: either the change is seen here or the observer is notified.
+        fence(Ordering::SeqCst);
+        Self(observers)
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<R: BlockingRbRef> BlockingObs<R> {
+    pub fn new(rb: R) -> Self {
+        Self {
+            base: Obs::new(rb),
+            timeout: None,
+        }
+    }
+
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Wait until at least `count` items are occupied.
+    ///
+    /// Returns [`WaitError::Closed`] if there are not enough items and producer is closed.
+    pub fn wait_occupied(&self, count: usize) -> Result<(), WaitError> {
+        debug_assert!(count <= self.capacity().get());
+        let _waiting = Waiting::new(&self.base.rb().observers);
+        for _ in wait_iter!(self) {
+            if let Some(res) = ready_or_closed(
+                self,
+                |this| !this.write_is_held(),
+                |this| (this.base.occupied_len() >= count).then_some(()),
+            ) {
+                return res;
+            }
+        }
+        Err(WaitError::TimedOut)
+    }
+
+    /// Wait until at least `count` slots are vacant.
+    ///
+    /// Returns [`WaitError::Closed`] if there are not enough vacant slots and consumer is closed.
+    pub fn wait_vacant(&self, count: usize) -> Result<(), WaitError> {
+        debug_assert!(count <= self.capacity().get());
+        let _waiting = Waiting::new(&self.base.rb().observers);
+        for _ in wait_iter!(self) {
+            if let Some(res) = ready_or_closed(
+                self,
+                |this| !this.read_is_held(),
+                |this| (this.base.vacant_len() >= count).then_some(()),
+            ) {
+                return res;
+            }
+        }
+        Err(WaitError::TimedOut)
+    }
+}
+
+impl<R: BlockingRbRef> Based for BlockingObs<R> {
+    type Base = Obs<R>;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
@@ -1,4 +1,4 @@
-use super::{BlockingWrap, WaitError};
+use super::{ready_or_closed, BlockingWrap, WaitError};
 use crate::{rb::BlockingRbRef, sync::Semaphore};
 use core::time::Duration;
 #[cfg(feature = "std")]
@@ -17,7 +17,10 @@ impl<R: BlockingRbRef> DelegateProducer for BlockingProd<R> {}
 
 macro_rules! wait_iter {
     ($self:expr) => {
-        $self.rb.rb().read.take_iter($self.timeout()).reset()
+        wait_iter!($self, $self.timeout())
+    };
+    ($self:expr, $timeout:expr) => {
+        $self.rb.rb().read.take_iter($timeout).reset()
     };
 }
 
@@ -35,19 +38,41 @@ impl<R: BlockingRbRef> BlockingProd<R> {
 
     pub fn wait_vacant(&mut self, count: usize) -> Result<(), WaitError> {
         debug_assert!(count <= self.rb().capacity().get());
+        let ready = |this: &Self| {
+            ready_or_closed(
+                this,
+                |this| this.is_closed(),
+                |this| (this.base.vacant_len() >= count).then_some(()),
+            )
+        };
+        if let Some(res) = self.spin_wait(ready) {
+            return res;
+        }
         for _ in wait_iter!(self) {
-            if self.base.vacant_len() >= count {
-                return Ok(());
-            }
-            if self.is_closed() {
-                return Err(WaitError::Closed);
+            if let Some(res) = ready(self) {
+                return res;
             }
         }
         Err(WaitError::TimedOut)
     }
 
-    pub fn push(&mut self, mut item: <Self as Observer>::Item) -> Result<(), (WaitError, <Self as Observer>::Item)> {
-        for _ in wait_iter!(self) {
+    pub fn push(&mut self, item: <Self as Observer>::Item) -> Result<(), (WaitError, <Self as Observer>::Item)> {
+        self.push_with_timeout(item, self.timeout())
+    }
+
+    /// Push single item waiting at most `timeout` for a vacant slot regardless of [`Self::timeout`].
+    ///
+    /// On failure the item is returned back along with the error.
+    pub fn try_push_timeout(&mut self, item: <Self as Observer>::Item, timeout: Duration) -> Result<(), (<Self as Observer>::Item, WaitError)> {
+        self.push_with_timeout(item, Some(timeout)).map_err(|(err, item)| (item, err))
+    }
+
+    fn push_with_timeout(
+        &mut self,
+        mut item: <Self as Observer>::Item,
+        timeout: Option<Duration>,
+    ) -> Result<(), (WaitError, <Self as Observer>::Item)> {
+        for _ in wait_iter!(self, timeout) {
             item = match self.base.try_push(item) {
                 Ok(()) => return Ok(()),
                 Err(item) => item,
@@ -12,13 +12,10 @@ fn main() {
     let (mut prod, mut cons) = rb.split();
 
     let pjh = thread::spawn(move || {
-        #[allow(unused_variables)]
-        let mut i = 0;
         let mut y = msg.next();
         while let Some(x) = y {
             if prod.try_push(x).is_ok() {
                 y = msg.next();
-                i += 1;
             }
         }
     });
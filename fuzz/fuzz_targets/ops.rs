@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ringbuf::fuzz::{check, Input};
+
+fuzz_target!(|input: Input| check(&input));
@@ -7,10 +7,14 @@ use super::{
 };
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
+#[cfg(all(feature = "alloc", not(feature = "portable-atomic"), target_has_atomic = "32"))]
+use core::sync::atomic::AtomicU32;
+#[cfg(all(feature = "alloc", feature = "portable-atomic"))]
+use portable_atomic::AtomicU32;
 
 /// Stack-allocated ring buffer with static capacity.
 ///
-/// *Capacity (`N`) must be greater than zero.*
+/// *Capacity (`N`) must be greater than zero and not exceed [`MAX_CAPACITY`](crate::storage::MAX_CAPACITY), this is checked at compile time.*
 pub type StaticRb<T, const N: usize> = SharedRb<Array<T, N>>;
 
 /// Alias for [`StaticRb`] producer.
@@ -30,3 +34,9 @@ pub type HeapProd<T> = CachingProd<Arc<HeapRb<T>>>;
 #[cfg(feature = "alloc")]
 /// Alias for [`HeapRb`] consumer.
 pub type HeapCons<T> = CachingCons<Arc<HeapRb<T>>>;
+
+/// Heap-allocated ring buffer with 32-bit indices.
+///
+/// *Capacity must be less than `2^31`.*
+#[cfg(all(feature = "alloc", any(feature = "portable-atomic", target_has_atomic = "32")))]
+pub type HeapRb32<T> = SharedRb<Heap<T>, AtomicU32>;
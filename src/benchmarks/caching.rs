@@ -0,0 +1,55 @@
+//! Single-item operations through the whole `CachingProd`/`CachingCons` stack.
+
+use crate::{storage::Array, traits::*, SharedRb};
+use test::{black_box, Bencher};
+
+const RB_SIZE: usize = 256;
+const BATCH_SIZE: usize = 100;
+
+#[bench]
+fn caching_try_push(b: &mut Bencher) {
+    let mut buf = SharedRb::<Array<u64, RB_SIZE>>::default();
+    let (mut prod, mut cons) = buf.split_ref();
+    b.iter(|| {
+        for i in 0..BATCH_SIZE {
+            prod.try_push(black_box(i as u64)).unwrap();
+        }
+        cons.skip(BATCH_SIZE);
+    });
+}
+
+#[bench]
+fn caching_try_pop(b: &mut Bencher) {
+    let mut buf = SharedRb::<Array<u64, RB_SIZE>>::default();
+    let (mut prod, mut cons) = buf.split_ref();
+    let data = [1; BATCH_SIZE];
+    b.iter(|| {
+        prod.push_slice(&data);
+        for _ in 0..BATCH_SIZE {
+            black_box(cons.try_pop().unwrap());
+        }
+    });
+}
+
+#[bench]
+fn caching_try_push_full(b: &mut Bencher) {
+    let mut buf = SharedRb::<Array<u64, RB_SIZE>>::default();
+    let (mut prod, _cons) = buf.split_ref();
+    prod.push_iter(0..);
+    b.iter(|| {
+        for i in 0..BATCH_SIZE {
+            black_box(prod.try_push(black_box(i as u64))).unwrap_err();
+        }
+    });
+}
+
+#[bench]
+fn caching_try_pop_empty(b: &mut Bencher) {
+    let mut buf = SharedRb::<Array<u64, RB_SIZE>>::default();
+    let (_prod, mut cons) = buf.split_ref();
+    b.iter(|| {
+        for _ in 0..BATCH_SIZE {
+            assert!(black_box(cons.try_pop()).is_none());
+        }
+    });
+}
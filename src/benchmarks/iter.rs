@@ -34,3 +34,37 @@ fn pop_iter_x1000(b: &mut Bencher) {
         unsafe { prod.advance_write_index(1000) };
     });
 }
+
+#[bench]
+fn get_as_slices_x1000(b: &mut Bencher) {
+    let buf = HeapRb::<i32>::new(RB_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; RB_SIZE / 2]);
+    cons.skip(RB_SIZE / 2);
+    prod.push_slice(&[1; 1000]);
+
+    b.iter(|| {
+        for i in 0..1000 {
+            let (left, right) = cons.as_slices();
+            black_box(if i < left.len() { &left[i] } else { &right[i - left.len()] });
+        }
+    });
+}
+
+#[bench]
+fn get_borrowed_x1000(b: &mut Bencher) {
+    let buf = HeapRb::<i32>::new(RB_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; RB_SIZE / 2]);
+    cons.skip(RB_SIZE / 2);
+    prod.push_slice(&[1; 1000]);
+
+    b.iter(|| {
+        let view = cons.borrow();
+        for i in 0..1000 {
+            black_box(&view[i]);
+        }
+    });
+}
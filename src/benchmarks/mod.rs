@@ -1,4 +1,9 @@
 mod base;
+mod caching;
 mod iter;
 mod parts;
+#[cfg(feature = "alloc")]
+mod pow2;
+#[cfg(all(feature = "prefetch", feature = "std"))]
+mod prefetch;
 mod slice;
@@ -0,0 +1,30 @@
+//! Heap storage is used to prevent compiler from optimizing division by known capacity.
+
+use crate::{rb::PowerOfTwoRb, storage::Heap, traits::*, LocalRb};
+use test::{black_box, Bencher};
+
+const RB_SIZE: usize = 256;
+
+#[bench]
+fn advance_modulo(b: &mut Bencher) {
+    let mut rb = LocalRb::<Heap<u64>>::new(black_box(RB_SIZE));
+    let (mut prod, cons) = rb.split_ref();
+    prod.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        unsafe { prod.advance_write_index(black_box(1)) };
+        unsafe { cons.advance_read_index(black_box(1)) };
+        black_box(cons.occupied_len());
+    });
+}
+
+#[bench]
+fn advance_masked(b: &mut Bencher) {
+    let mut rb = PowerOfTwoRb::new(LocalRb::<Heap<u64>>::new(black_box(RB_SIZE)));
+    let (mut prod, cons) = rb.split_ref();
+    prod.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        unsafe { prod.advance_write_index(black_box(1)) };
+        unsafe { cons.advance_read_index(black_box(1)) };
+        black_box(cons.occupied_len());
+    });
+}
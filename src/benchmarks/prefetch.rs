@@ -0,0 +1,65 @@
+//! Cross-thread streaming with and without [`Producer::prefetch_written`] hint.
+//!
+//! Consumer runs in a separate thread, so the benefit depends on the number of cores and cache topology.
+//! Run with `cargo +nightly bench --features bench,prefetch -- stream`.
+
+use crate::{traits::*, HeapRb};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+use test::{black_box, Bencher};
+
+const RB_SIZE: usize = 1024;
+const BATCH_SIZE: usize = 4096;
+const CHUNK_SIZE: usize = 64;
+
+fn stream(b: &mut Bencher, prefetch: bool) {
+    let (mut prod, mut cons) = HeapRb::<u64>::new(RB_SIZE).split();
+    let done = Arc::new(AtomicBool::new(false));
+
+    let cjh = thread::spawn({
+        let done = done.clone();
+        move || {
+            let mut buf = [0; CHUNK_SIZE];
+            while !done.load(Ordering::Relaxed) {
+                if cons.pop_slice(&mut buf) == 0 {
+                    thread::yield_now();
+                }
+                black_box(&buf);
+            }
+        }
+    });
+
+    let data = [1; CHUNK_SIZE];
+    b.iter(|| {
+        let mut count = 0;
+        while count < BATCH_SIZE {
+            let n = prod.push_slice(&data);
+            if n == 0 {
+                thread::yield_now();
+                continue;
+            }
+            if prefetch {
+                prod.prefetch_written(n);
+            }
+            count += n;
+        }
+    });
+
+    done.store(true, Ordering::Relaxed);
+    cjh.join().unwrap();
+}
+
+#[bench]
+fn stream_plain(b: &mut Bencher) {
+    stream(b, false);
+}
+
+#[bench]
+fn stream_prefetch(b: &mut Bencher) {
+    stream(b, true);
+}
@@ -41,3 +41,27 @@ fn slice_x1000(b: &mut Bencher) {
     });
     black_box(data);
 }
+
+#[bench]
+fn slice_x4(b: &mut Bencher) {
+    let mut rb = HeapRb::<u64>::new(RB_SIZE);
+    rb.push_slice(&[1; RB_SIZE / 2 + 1]);
+    let mut data = [1; 4];
+    b.iter(|| {
+        rb.push_slice(&data);
+        rb.pop_slice(&mut data);
+        black_box(data);
+    });
+}
+
+#[bench]
+fn frame_x4(b: &mut Bencher) {
+    let mut rb = HeapRb::<u64>::new(RB_SIZE);
+    rb.push_slice(&[1; RB_SIZE / 2 + 1]);
+    let mut data = [1; 4];
+    b.iter(|| {
+        rb.push_frame(&data);
+        data = rb.pop_frame().unwrap();
+        black_box(data);
+    });
+}
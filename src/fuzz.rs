@@ -0,0 +1,78 @@
+//! Differential testing of ring buffer against [`VecDeque`] model.
+//!
+//! Used by fuzzing targets in `fuzz` directory.
+//! This module is hidden from docs and may change in any release without notice.
+
+use crate::{traits::*, HeapRb};
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+/// Operation applied to both the ring buffer and the model.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Op {
+    Push(u8),
+    Pop,
+    Skip(usize),
+    PushSlice(Vec<u8>),
+    PopSlice(u8),
+    PushOverwrite(u8),
+    Clear,
+}
+
+/// Fuzzing input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Input {
+    /// Ring buffer capacity minus one.
+    pub capacity: u8,
+    pub ops: Vec<Op>,
+}
+
+/// Apply operations to a new ring buffer and to a [`VecDeque`] model, panic on any mismatch.
+pub fn check(input: &Input) {
+    let capacity = input.capacity as usize + 1;
+    let mut rb = HeapRb::<u8>::new(capacity);
+    let mut model = VecDeque::<u8>::with_capacity(capacity);
+
+    for op in input.ops.iter() {
+        match op {
+            Op::Push(x) => {
+                let expected = if model.len() < capacity {
+                    model.push_back(*x);
+                    Ok(())
+                } else {
+                    Err(*x)
+                };
+                assert_eq!(rb.try_push(*x), expected);
+            }
+            Op::Pop => assert_eq!(rb.try_pop(), model.pop_front()),
+            Op::Skip(n) => {
+                let count = usize::min(*n, model.len());
+                model.drain(..count);
+                assert_eq!(rb.skip(*n), count);
+            }
+            Op::PushSlice(elems) => {
+                let count = usize::min(elems.len(), capacity - model.len());
+                model.extend(&elems[..count]);
+                assert_eq!(rb.push_slice(elems), count);
+            }
+            Op::PopSlice(n) => {
+                let mut buf = vec![0; *n as usize];
+                let count = rb.pop_slice(&mut buf);
+                assert_eq!(count, usize::min(buf.len(), model.len()));
+                assert!(buf[..count].iter().copied().eq(model.drain(..count)));
+            }
+            Op::PushOverwrite(x) => {
+                let expected = if model.len() == capacity { model.pop_front() } else { None };
+                model.push_back(*x);
+                assert_eq!(rb.push_overwrite(*x), expected);
+            }
+            Op::Clear => {
+                assert_eq!(rb.clear(), model.len());
+                model.clear();
+            }
+        }
+        assert_eq!(rb.occupied_len(), model.len());
+        assert!(rb.iter().eq(model.iter()));
+    }
+}
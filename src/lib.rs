@@ -157,6 +157,14 @@ extern crate std;
 
 /// Shortcuts for frequently used types.
 mod alias;
+/// Differential testing against model implementation.
+///
+/// Not a part of public API and not covered by semver guarantees.
+#[doc(hidden)]
+#[cfg(any(feature = "arbitrary", all(test, feature = "alloc")))]
+pub mod fuzz;
+/// Commonly used traits and types.
+pub mod prelude;
 /// Ring buffer implementations.
 pub mod rb;
 /// Storage types.
@@ -176,7 +184,7 @@ mod tests;
 pub use alias::*;
 pub use rb::{LocalRb, SharedRb};
 pub use traits::{consumer, producer};
-pub use transfer::transfer;
+pub use transfer::{broadcast, transfer, transfer_all};
 pub use wrap::{CachingCons, CachingProd, Cons, Obs, Prod};
 
 #[cfg(feature = "bench")]
@@ -0,0 +1,20 @@
+//! Re-exports traits and commonly used types.
+//!
+//! ```
+//! # extern crate ringbuf;
+//! use ringbuf::prelude::*;
+//! # fn main() {
+//! let (mut prod, mut cons) = HeapRb::<i32>::new(2).split();
+//! prod.try_push(1).unwrap();
+//! assert_eq!(cons.try_pop(), Some(1));
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::alias::{HeapCons, HeapProd, HeapRb};
+pub use crate::{
+    alias::{StaticCons, StaticProd, StaticRb},
+    rb::{LocalRb, SharedRb},
+    traits::*,
+    wrap::{CachingCons, CachingProd, Cons, Obs, Prod},
+};
@@ -1,12 +1,18 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::rb_impl_init,
+    utils::{ranges, ranges_mirrored},
+};
+#[cfg(feature = "alloc")]
+use crate::storage::Heap;
 #[cfg(feature = "alloc")]
 use crate::traits::Split;
 use crate::{
-    storage::Storage,
+    storage::{Array, Storage, MAX_CAPACITY},
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::{impl_observer_traits, Observer},
         producer::{impl_producer_traits, Producer},
-        Observer, RingBuffer, SplitRef,
+        RingBuffer, SplitRef,
     },
     wrap::{Cons, Prod},
 };
@@ -22,6 +28,8 @@ use core::{
 struct Endpoint {
     index: Cell<usize>,
     held: Cell<bool>,
+    #[cfg(feature = "metrics")]
+    total: Cell<u64>,
 }
 
 impl Endpoint {
@@ -29,6 +37,8 @@ impl Endpoint {
         Self {
             index: Cell::new(index),
             held: Cell::new(false),
+            #[cfg(feature = "metrics")]
+            total: Cell::new(0),
         }
     }
 }
@@ -49,8 +59,10 @@ impl<S: Storage> LocalRb<S> {
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    #[track_caller]
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
+        assert!(storage.len() <= MAX_CAPACITY, "Capacity is too large");
         Self {
             storage,
             read: Endpoint::new(read),
@@ -85,12 +97,22 @@ impl<S: Storage + ?Sized> Observer for LocalRb<S> {
         self.write.index.get()
     }
 
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
-        let (first, second) = ranges(self.capacity(), start, end);
+        let (first, second) = if S::MIRRORED {
+            ranges_mirrored(self.capacity(), start, end)
+        } else {
+            ranges(self.capacity(), start, end)
+        };
         (self.storage.slice(first), self.storage.slice(second))
     }
+    #[inline]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<S::Item>], &mut [MaybeUninit<S::Item>]) {
-        let (first, second) = ranges(self.capacity(), start, end);
+        let (first, second) = if S::MIRRORED {
+            ranges_mirrored(self.capacity(), start, end)
+        } else {
+            ranges(self.capacity(), start, end)
+        };
         (self.storage.slice_mut(first), self.storage.slice_mut(second))
     }
 
@@ -102,6 +124,21 @@ impl<S: Storage + ?Sized> Observer for LocalRb<S> {
     fn write_is_held(&self) -> bool {
         self.write.held.get()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        S::MIRRORED
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.write.total.get()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.read.total.get()
+    }
 }
 
 impl<S: Storage + ?Sized> Producer for LocalRb<S> {
@@ -109,6 +146,12 @@ impl<S: Storage + ?Sized> Producer for LocalRb<S> {
     unsafe fn set_write_index(&self, value: usize) {
         self.write.index.set(value);
     }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    unsafe fn advance_write_index(&self, count: usize) {
+        self.write.total.set(self.write.total.get() + count as u64);
+        self.set_write_index((self.write_index() + count) % (2 * self.capacity().get()));
+    }
 }
 
 impl<S: Storage + ?Sized> Consumer for LocalRb<S> {
@@ -116,6 +159,12 @@ impl<S: Storage + ?Sized> Consumer for LocalRb<S> {
     unsafe fn set_read_index(&self, value: usize) {
         self.read.index.set(value);
     }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    unsafe fn advance_read_index(&self, count: usize) {
+        self.read.total.set(self.read.total.get() + count as u64);
+        self.set_read_index((self.read_index() + count) % (2 * self.capacity().get()));
+    }
 }
 
 impl<S: Storage + ?Sized> RingBuffer for LocalRb<S> {
@@ -127,6 +176,11 @@ impl<S: Storage + ?Sized> RingBuffer for LocalRb<S> {
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.write.held.replace(flag)
     }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.read.total.set(self.read.total.get() + count as u64);
+    }
 }
 
 impl<S: Storage + ?Sized> Drop for LocalRb<S> {
@@ -135,6 +189,34 @@ impl<S: Storage + ?Sized> Drop for LocalRb<S> {
     }
 }
 
+/// Creates a new ring buffer of the same capacity containing clones of items in order from the eldest one.
+///
+/// Only occupied items are cloned, vacant slots of the copy remain uninitialized.
+impl<T: Clone, const N: usize> Clone for LocalRb<Array<T, N>> {
+    fn clone(&self) -> Self {
+        let mut rb = Self::default();
+        for item in self.iter() {
+            // Each item is committed separately to drop already cloned items if `clone` panics.
+            let _ = rb.try_push(item.clone());
+        }
+        rb
+    }
+}
+
+/// Creates a new ring buffer of the same capacity containing clones of items in order from the eldest one.
+///
+/// Only occupied items are cloned, vacant slots of the copy remain uninitialized.
+#[cfg(feature = "alloc")]
+impl<T: Clone> Clone for LocalRb<Heap<T>> {
+    fn clone(&self) -> Self {
+        let mut rb = Self::new(self.capacity().get());
+        for item in self.iter() {
+            let _ = rb.try_push(item.clone());
+        }
+        rb
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<S: Storage> Split for LocalRb<S> {
     type Prod = Prod<Rc<Self>>;
@@ -173,6 +255,7 @@ impl<S: Storage + ?Sized> SplitRef for LocalRb<S> {
 
 rb_impl_init!(LocalRb);
 
+impl_observer_traits!([S: Storage + ?Sized] LocalRb<S>);
 impl_producer_traits!(LocalRb<S: Storage>);
 impl_consumer_traits!(LocalRb<S: Storage>);
 
@@ -1,35 +1,121 @@
 macro_rules! rb_impl_init {
-    ($type:ident) => {
-        impl<T, const N: usize> Default for $type<crate::storage::Array<T, N>> {
+    ($type:ident $(, $param:ident : $bound:path)*) => {
+        impl<T, const N: usize $(, $param: $bound)*> Default for $type<crate::storage::Array<T, N> $(, $param)*> {
             fn default() -> Self {
+                let () = crate::storage::StaticCapacity::<N $(, $param)*>::VALID;
+                unsafe { Self::from_raw_parts(crate::utils::uninit_array().into(), usize::default(), usize::default()) }
+            }
+        }
+
+        impl<T, const N: usize, A $(, $param: $bound)*> Default for $type<crate::storage::AlignedArray<T, N, A> $(, $param)*> {
+            fn default() -> Self {
+                let () = crate::storage::StaticCapacity::<N $(, $param)*>::VALID;
                 unsafe { Self::from_raw_parts(crate::utils::uninit_array().into(), usize::default(), usize::default()) }
             }
         }
 
         impl<T, const N: usize> From<[T; N]> for $type<crate::storage::Array<T, N>> {
             fn from(value: [T; N]) -> Self {
+                let () = crate::storage::StaticCapacity::<N>::VALID;
                 let (read, write) = (0, value.len());
                 unsafe { Self::from_raw_parts(crate::utils::array_to_uninit(value).into(), read, write) }
             }
         }
 
+        impl<T, const N: usize $(, $param: $bound)*> $type<crate::storage::Array<T, N> $(, $param)*> {
+            /// Capacity of the ring buffer.
+            ///
+            /// Can be used in const contexts, e.g. as a length of other arrays.
+            pub const CAPACITY: usize = {
+                let () = crate::storage::StaticCapacity::<N $(, $param)*>::VALID;
+                N
+            };
+        }
+
+        impl<T, const N: usize> $type<crate::storage::Array<T, N>> {
+            /// Creates a full ring buffer containing `items` in order from the eldest one.
+            ///
+            /// Same as `Self::from(items)`.
+            pub fn from_full_array(items: [T; N]) -> Self {
+                Self::from(items)
+            }
+            /// Creates a ring buffer containing first `len` of `items` in order from the eldest one.
+            ///
+            /// Remaining items are dropped.
+            ///
+            /// *Panics if `len` is greater than `N`.*
+            #[track_caller]
+            pub fn from_array_prefix(items: [T; N], len: usize) -> Self {
+                let () = crate::storage::StaticCapacity::<N>::VALID;
+                assert!(len <= N, "Prefix length ({}) is greater than capacity ({})", len, N);
+                let mut data = crate::utils::array_to_uninit(items);
+                for item in &mut data[len..] {
+                    unsafe { item.assume_init_drop() };
+                }
+                unsafe { Self::from_raw_parts(data.into(), 0, len) }
+            }
+        }
+
         #[cfg(feature = "alloc")]
-        impl<T> $type<crate::storage::Heap<T>> {
+        impl<T $(, $param: $bound)*> $type<crate::storage::Heap<T> $(, $param)*> {
             /// Creates a new instance of a ring buffer.
             ///
-            /// *Panics if allocation failed or `capacity` is zero.*
+            /// *Panics if allocation failed, `capacity` is zero or greater than [`MAX_CAPACITY`](crate::storage::MAX_CAPACITY).*
+            #[track_caller]
             pub fn new(capacity: usize) -> Self {
                 unsafe { Self::from_raw_parts(crate::storage::Heap::<T>::new(capacity), usize::default(), usize::default()) }
             }
+            /// Creates a new instance of a ring buffer with storage aligned at least to `align` bytes.
+            ///
+            /// *Panics if allocation failed, `capacity` is zero or `align` is not a power of two.*
+            #[track_caller]
+            pub fn new_aligned(capacity: usize, align: usize) -> Self {
+                unsafe {
+                    Self::from_raw_parts(
+                        crate::storage::Heap::<T>::new_aligned(capacity, align),
+                        usize::default(),
+                        usize::default(),
+                    )
+                }
+            }
             /// Creates a new instance of a ring buffer returning an error if allocation failed.
             ///
             /// *Panics if `capacity` is zero.*
+            #[track_caller]
             pub fn try_new(capacity: usize) -> Result<Self, alloc::collections::TryReserveError> {
                 let mut vec = alloc::vec::Vec::<core::mem::MaybeUninit<T>>::new();
                 vec.try_reserve_exact(capacity)?;
                 unsafe { vec.set_len(capacity) };
                 Ok(unsafe { Self::from_raw_parts(vec.into_boxed_slice().into(), usize::default(), usize::default()) })
             }
+            /// Replaces storage with a new one of `new_capacity` preserving items in order from the eldest one.
+            ///
+            /// If there are more items than `new_capacity` then the most recent items that don't fit are dropped.
+            ///
+            /// *Panics if allocation failed or `new_capacity` is zero.*
+            #[track_caller]
+            pub fn resize(&mut self, new_capacity: usize) {
+                let mut rb = Self::new(new_capacity);
+                crate::traits::Producer::push_iter(&mut rb, crate::traits::Consumer::pop_iter(self));
+                *self = rb;
+            }
+        }
+
+        #[cfg(all(feature = "mmap", any(target_os = "linux", target_os = "android")))]
+        impl<T> $type<crate::storage::MirroredHeap<T>> {
+            /// Creates a new instance of a ring buffer with mirrored storage of at least `capacity` items.
+            ///
+            /// *Panics if `capacity` is zero.*
+            #[track_caller]
+            pub fn new_mirrored(capacity: usize) -> std::io::Result<Self> {
+                Ok(unsafe {
+                    Self::from_raw_parts(
+                        crate::storage::MirroredHeap::<T>::new(capacity)?,
+                        usize::default(),
+                        usize::default(),
+                    )
+                })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -47,6 +133,52 @@ macro_rules! rb_impl_init {
                 unsafe { Self::from_raw_parts(crate::utils::boxed_slice_to_uninit(value).into(), read, write) }
             }
         }
+
+        /// Appends items from an iterator until the ring buffer is full.
+        ///
+        /// Items that don't fit are not taken from the iterator and dropped along with it.
+        impl<S: crate::storage::Storage + ?Sized $(, $param: $bound)*> Extend<S::Item> for $type<S $(, $param)*> {
+            fn extend<It: IntoIterator<Item = S::Item>>(&mut self, iter: It) {
+                crate::traits::Producer::push_iter(self, iter.into_iter());
+            }
+        }
+
+        /// Collects all items into a new heap-allocated ring buffer.
+        ///
+        /// Capacity of the ring buffer is exactly the number of items, or one if the iterator is empty.
+        /// Items are collected into [`Vec`](alloc::vec::Vec) first, so storage is allocated once for [`ExactSizeIterator`]
+        /// and grows as needed otherwise.
+        #[cfg(feature = "alloc")]
+        impl<T> FromIterator<T> for $type<crate::storage::Heap<T>> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let vec = alloc::vec::Vec::from_iter(iter);
+                if vec.is_empty() {
+                    Self::new(1)
+                } else {
+                    Self::from(vec.into_boxed_slice())
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<S: crate::storage::Storage + ?Sized $(, $param: $bound)*> serde::Serialize for $type<S $(, $param)*>
+        where
+            S::Item: serde::Serialize,
+        {
+            fn serialize<Z: serde::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+                crate::rb::serde::serialize(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for $type<crate::storage::Heap<T>> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (capacity, items) = crate::rb::serde::deserialize(deserializer)?;
+                let mut rb = Self::try_new(capacity).map_err(<D::Error as serde::de::Error>::custom)?;
+                crate::traits::Producer::push_iter(&mut rb, items.into_iter());
+                Ok(rb)
+            }
+        }
     };
 }
 
@@ -1,11 +1,18 @@
 /// Single-threaded ring buffer implementation.
 pub mod local;
 mod macros;
+/// Ring buffer with fast index arithmetic.
+pub mod pow2;
+#[cfg(feature = "serde")]
+mod serde;
 /// Multi-threaded ring buffer implementation.
 pub mod shared;
 mod traits;
 mod utils;
 
 pub use local::LocalRb;
-pub use shared::SharedRb;
+pub use pow2::PowerOfTwoRb;
+#[cfg(feature = "alloc")]
+pub use shared::Stoppable;
+pub use shared::{AtomicIndex, SharedRb};
 pub use traits::*;
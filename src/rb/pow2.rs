@@ -0,0 +1,188 @@
+use crate::{
+    traits::{observer::impl_observer_traits, Based, Consumer, Observer, Producer, RingBuffer, SplitRef},
+    wrap::{Cons, Prod},
+};
+#[cfg(all(feature = "metrics", not(feature = "portable-atomic")))]
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::{mem::MaybeUninit, num::NonZeroUsize};
+#[cfg(all(feature = "metrics", feature = "portable-atomic"))]
+use portable_atomic::{AtomicU64, Ordering};
+
+/// Ring buffer with power-of-two capacity.
+///
+/// Wraps another ring buffer and replaces division in index arithmetic with bit masking.
+/// Affects index advancing and computation of occupied and vacant lengths performed through this wrapper or its [`split_ref`](`SplitRef::split_ref`) parts.
+pub struct PowerOfTwoRb<B: RingBuffer> {
+    base: B,
+    /// `2 * capacity - 1`
+    mask: usize,
+    /// Items passed through this wrapper, the base ring buffer doesn't count them.
+    #[cfg(feature = "metrics")]
+    total_written: AtomicU64,
+    #[cfg(feature = "metrics")]
+    total_read: AtomicU64,
+}
+
+impl<B: RingBuffer> PowerOfTwoRb<B> {
+    /// Wrap `base` ring buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if capacity of `base` is not a power of two.
+    #[track_caller]
+    pub fn new(base: B) -> Self {
+        let capacity = base.capacity().get();
+        assert!(capacity.is_power_of_two(), "Capacity ({}) is not a power of two", capacity);
+        Self {
+            base,
+            mask: 2 * capacity - 1,
+            #[cfg(feature = "metrics")]
+            total_written: AtomicU64::new(0),
+            #[cfg(feature = "metrics")]
+            total_read: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the underlying ring buffer.
+    pub fn into_inner(self) -> B {
+        self.base
+    }
+}
+
+impl<B: RingBuffer> Based for PowerOfTwoRb<B> {
+    type Base = B;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<B: RingBuffer> Observer for PowerOfTwoRb<B> {
+    type Item = B::Item;
+
+    #[inline]
+    fn capacity(&self) -> NonZeroUsize {
+        self.base.capacity()
+    }
+
+    #[inline]
+    fn read_index(&self) -> usize {
+        self.base.read_index()
+    }
+    #[inline]
+    fn write_index(&self) -> usize {
+        self.base.write_index()
+    }
+    #[inline]
+    fn indices(&self) -> (usize, usize) {
+        self.base.indices()
+    }
+
+    #[inline]
+    unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
+        self.base.unsafe_slices(start, end)
+    }
+    #[inline]
+    unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
+        self.base.unsafe_slices_mut(start, end)
+    }
+
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.base.read_is_held()
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.base.write_is_held()
+    }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.base.is_mirrored()
+    }
+
+    #[inline]
+    fn occupied_len(&self) -> usize {
+        self.write_index().wrapping_sub(self.read_index()) & self.mask
+    }
+    #[inline]
+    fn vacant_len(&self) -> usize {
+        (self.capacity().get() + self.read_index()).wrapping_sub(self.write_index()) & self.mask
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.base.total_written() + self.total_written.load(Ordering::Relaxed)
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.base.total_read() + self.total_read.load(Ordering::Relaxed)
+    }
+}
+
+impl<B: RingBuffer> Producer for PowerOfTwoRb<B> {
+    #[inline]
+    unsafe fn set_write_index(&self, value: usize) {
+        self.base.set_write_index(value);
+    }
+    #[inline]
+    unsafe fn advance_write_index(&self, count: usize) {
+        #[cfg(feature = "metrics")]
+        self.total_written.fetch_add(count as u64, Ordering::Relaxed);
+        self.base.set_write_index(self.base.write_index().wrapping_add(count) & self.mask);
+    }
+}
+
+impl<B: RingBuffer> Consumer for PowerOfTwoRb<B> {
+    #[inline]
+    unsafe fn set_read_index(&self, value: usize) {
+        self.base.set_read_index(value);
+    }
+    #[inline]
+    unsafe fn advance_read_index(&self, count: usize) {
+        #[cfg(feature = "metrics")]
+        self.total_read.fetch_add(count as u64, Ordering::Relaxed);
+        self.base.set_read_index(self.base.read_index().wrapping_add(count) & self.mask);
+    }
+}
+
+impl<B: RingBuffer> RingBuffer for PowerOfTwoRb<B> {
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        self.base.hold_read(flag)
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.base.hold_write(flag)
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.total_read.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+impl<B: RingBuffer> SplitRef for PowerOfTwoRb<B> {
+    type RefProd<'a> = Prod<&'a Self> where Self: 'a;
+    type RefCons<'a> = Cons<&'a Self> where Self: 'a;
+
+    fn split_ref(&mut self) -> (Self::RefProd<'_>, Self::RefCons<'_>) {
+        (Prod::new(self), Cons::new(self))
+    }
+}
+
+impl_observer_traits!([B: RingBuffer] PowerOfTwoRb<B>);
+
+impl<B: RingBuffer> AsRef<Self> for PowerOfTwoRb<B> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<B: RingBuffer> AsMut<Self> for PowerOfTwoRb<B> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
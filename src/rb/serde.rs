@@ -0,0 +1,112 @@
+use crate::traits::Consumer;
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+const NAME: &str = "RingBuffer";
+const FIELDS: &[&str] = &["capacity", "items"];
+
+/// Occupied items in order from the eldest one.
+struct Items<'a, T>(&'a [T], &'a [T]);
+
+impl<T: Serialize> Serialize for Items<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().chain(self.1))
+    }
+}
+
+/// Serializes capacity and occupied items of the ring buffer.
+///
+/// Indices of the ring buffer are not modified.
+pub(crate) fn serialize<C: Consumer + ?Sized, S: Serializer>(rb: &C, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C::Item: Serialize,
+{
+    let (left, right) = rb.as_slices();
+    let mut state = serializer.serialize_struct(NAME, FIELDS.len())?;
+    state.serialize_field("capacity", &rb.capacity().get())?;
+    state.serialize_field("items", &Items(left, right))?;
+    state.end()
+}
+
+/// Deserializes capacity and items of the ring buffer.
+///
+/// Returns an error if capacity is zero or number of items exceeds capacity.
+pub(crate) fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<(usize, Vec<T>), D::Error> {
+    deserializer.deserialize_struct(NAME, FIELDS, RbVisitor(PhantomData))
+}
+
+struct RbVisitor<T>(PhantomData<T>);
+
+impl<T> RbVisitor<T> {
+    fn check<E: de::Error>(capacity: usize, items: Vec<T>) -> Result<(usize, Vec<T>), E> {
+        if capacity == 0 {
+            Err(E::invalid_value(de::Unexpected::Unsigned(0), &"non-zero capacity"))
+        } else if items.len() > capacity {
+            Err(E::invalid_length(items.len(), &"number of items not exceeding capacity"))
+        } else {
+            Ok((capacity, items))
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RbVisitor<T> {
+    type Value = (usize, Vec<T>);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ring buffer capacity and items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let capacity = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let items = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::check(capacity, items)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut capacity, mut items) = (None, None);
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Capacity if capacity.is_some() => return Err(de::Error::duplicate_field("capacity")),
+                Field::Capacity => capacity = Some(map.next_value()?),
+                Field::Items if items.is_some() => return Err(de::Error::duplicate_field("items")),
+                Field::Items => items = Some(map.next_value()?),
+            }
+        }
+        let capacity = capacity.ok_or_else(|| de::Error::missing_field("capacity"))?;
+        let items = items.ok_or_else(|| de::Error::missing_field("items"))?;
+        Self::check(capacity, items)
+    }
+}
+
+enum Field {
+    Capacity,
+    Items,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("`capacity` or `items`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "capacity" => Ok(Field::Capacity),
+                    "items" => Ok(Field::Items),
+                    _ => Err(E::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
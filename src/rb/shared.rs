@@ -1,28 +1,80 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::rb_impl_init,
+    utils::{ranges, ranges_mirrored},
+};
+#[cfg(feature = "alloc")]
+use crate::traits::{consumer::DelegateConsumer, observer::DelegateObserver, producer::DelegateProducer, Based, Split};
 #[cfg(feature = "alloc")]
-use crate::traits::Split;
+use crate::wrap::Wrap;
 use crate::{
-    storage::Storage,
+    storage::{Array, Slice, Storage, MAX_CAPACITY},
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::{impl_observer_traits, Observer},
         producer::{impl_producer_traits, Producer},
-        Observer, RingBuffer, SplitRef,
+        RingBuffer, SplitRef,
     },
     wrap::{CachingCons, CachingProd},
 };
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, sync::Arc};
+#[cfg(all(feature = "metrics", not(feature = "portable-atomic")))]
+use core::sync::atomic::AtomicU64;
 #[cfg(not(feature = "portable-atomic"))]
 use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use core::{
-    mem::{ManuallyDrop, MaybeUninit},
+    mem::{self, ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
 };
 use crossbeam_utils::CachePadded;
+#[cfg(all(feature = "metrics", feature = "portable-atomic"))]
+use portable_atomic::AtomicU64;
 #[cfg(feature = "portable-atomic")]
 use portable_atomic::{AtomicBool, AtomicUsize, Ordering};
 
+/// Atomic integer used to store [`SharedRb`] indices.
+///
+/// Indices are loaded and stored as `usize`, so all index arithmetic is done at full width.
+pub trait AtomicIndex: Send + Sync {
+    /// Maximum index value that can be stored.
+    const MAX: usize;
+
+    /// Create a new atomic index.
+    fn new(value: usize) -> Self;
+    /// Load index value.
+    fn load(&self, order: Ordering) -> usize;
+    /// Store index value.
+    fn store(&self, value: usize, order: Ordering);
+}
+
+macro_rules! impl_atomic_index {
+    ($atomic:ty, $int:ty) => {
+        impl AtomicIndex for $atomic {
+            const MAX: usize = <$int>::MAX as usize;
+
+            #[inline]
+            fn new(value: usize) -> Self {
+                <$atomic>::new(value as $int)
+            }
+            #[inline]
+            fn load(&self, order: Ordering) -> usize {
+                <$atomic>::load(self, order) as usize
+            }
+            #[inline]
+            fn store(&self, value: usize, order: Ordering) {
+                <$atomic>::store(self, value as $int, order)
+            }
+        }
+    };
+}
+
+impl_atomic_index!(AtomicUsize, usize);
+#[cfg(all(not(feature = "portable-atomic"), target_has_atomic = "32"))]
+impl_atomic_index!(core::sync::atomic::AtomicU32, u32);
+#[cfg(feature = "portable-atomic")]
+impl_atomic_index!(portable_atomic::AtomicU32, u32);
+
 /// Ring buffer that can be shared between threads.
 ///
 /// Note that there is no explicit requirement of `T: Send`. Instead ring buffer will work just fine even with `T: !Send`
@@ -47,29 +99,41 @@ thread::spawn(move || {
 ```
 "##
 )]
-pub struct SharedRb<S: Storage + ?Sized> {
-    read_index: CachePadded<AtomicUsize>,
-    write_index: CachePadded<AtomicUsize>,
+// Storage is placed last and the layout of other fields doesn't depend on it, `in_place` and `attach` rely on that.
+#[repr(C)]
+pub struct SharedRb<S: Storage + ?Sized, I: AtomicIndex = AtomicUsize> {
+    read_index: CachePadded<I>,
+    write_index: CachePadded<I>,
     read_held: AtomicBool,
     write_held: AtomicBool,
+    #[cfg(feature = "metrics")]
+    total_read: AtomicU64,
+    #[cfg(feature = "metrics")]
+    total_written: AtomicU64,
     storage: S,
 }
 
-impl<S: Storage> SharedRb<S> {
+impl<S: Storage, I: AtomicIndex> SharedRb<S, I> {
     /// Constructs ring buffer from storage and indices.
     ///
     /// # Safety
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    #[track_caller]
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
+        assert!(storage.len() <= usize::min(MAX_CAPACITY, I::MAX / 2), "Capacity is too large");
         Self {
             storage,
-            read_index: CachePadded::new(AtomicUsize::new(read)),
-            write_index: CachePadded::new(AtomicUsize::new(write)),
+            read_index: CachePadded::new(I::new(read)),
+            write_index: CachePadded::new(I::new(write)),
             read_held: AtomicBool::new(false),
             write_held: AtomicBool::new(false),
+            #[cfg(feature = "metrics")]
+            total_read: AtomicU64::new(0),
+            #[cfg(feature = "metrics")]
+            total_written: AtomicU64::new(0),
         }
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
@@ -83,7 +147,71 @@ impl<S: Storage> SharedRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Observer for SharedRb<S> {
+impl<T, I: AtomicIndex> SharedRb<Slice<T>, I> {
+    /// Alignment of memory region required by [`Self::in_place`].
+    pub fn in_place_align() -> usize {
+        mem::align_of::<SharedRb<Array<T, 0>, I>>()
+    }
+    /// Size of memory region sufficient for [`Self::in_place`] ring buffer with `item_count` capacity.
+    ///
+    /// *Panics if the size overflows `usize`.*
+    #[track_caller]
+    pub fn in_place_size(item_count: usize) -> usize {
+        let align = Self::in_place_align();
+        item_count
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| size.checked_add(mem::size_of::<SharedRb<Array<T, 0>, I>>()))
+            .and_then(|size| size.checked_next_multiple_of(align))
+            .expect("Region size overflow")
+    }
+
+    /// Constructs an empty ring buffer with `item_count` capacity inside of the memory `region`.
+    ///
+    /// Indices are placed at the beginning of the region followed by items, so the whole ring buffer can be accessed by a single pointer
+    /// (e.g. when the region is shared between processes, see [`Self::attach`]).
+    ///
+    /// The ring buffer is never dropped, so items remaining in it when the region is released are leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item_count` is zero or greater than [`MAX_CAPACITY`] (or the limit of index type),
+    /// if `region` is not aligned to [`Self::in_place_align`] or if its length is less than [`Self::in_place_size`].
+    #[track_caller]
+    pub fn in_place(region: &mut [u8], item_count: usize) -> &mut Self {
+        assert!(item_count > 0, "Capacity must be greater than zero");
+        assert!(item_count <= usize::min(MAX_CAPACITY, I::MAX / 2), "Capacity is too large");
+        assert_eq!(region.as_ptr().align_offset(Self::in_place_align()), 0, "Region is misaligned");
+        assert!(region.len() >= Self::in_place_size(item_count), "Region is too small");
+        unsafe {
+            let this = Self::region_ptr(region.as_mut_ptr(), item_count);
+            ptr::addr_of_mut!((*this).read_index).write(CachePadded::new(I::new(0)));
+            ptr::addr_of_mut!((*this).write_index).write(CachePadded::new(I::new(0)));
+            ptr::addr_of_mut!((*this).read_held).write(AtomicBool::new(false));
+            ptr::addr_of_mut!((*this).write_held).write(AtomicBool::new(false));
+            #[cfg(feature = "metrics")]
+            ptr::addr_of_mut!((*this).total_read).write(AtomicU64::new(0));
+            #[cfg(feature = "metrics")]
+            ptr::addr_of_mut!((*this).total_written).write(AtomicU64::new(0));
+            &mut *this
+        }
+    }
+    /// Gets the ring buffer previously constructed by [`Self::in_place`] at the beginning of the memory `region`.
+    ///
+    /// # Safety
+    ///
+    /// `region` must point to the ring buffer constructed by [`Self::in_place`] with the same `T` and `item_count`.
+    /// The region must remain valid for `'a`.
+    pub unsafe fn attach<'a>(region: *mut u8, item_count: usize) -> &'a Self {
+        &*Self::region_ptr(region, item_count)
+    }
+
+    fn region_ptr(region: *mut u8, item_count: usize) -> *mut Self {
+        // Slice length becomes the length of the trailing storage.
+        ptr::slice_from_raw_parts_mut(region, item_count) as *mut Self
+    }
+}
+
+impl<S: Storage + ?Sized, I: AtomicIndex> Observer for SharedRb<S, I> {
     type Item = S::Item;
 
     #[inline]
@@ -99,13 +227,37 @@ impl<S: Storage + ?Sized> Observer for SharedRb<S> {
     fn write_index(&self) -> usize {
         self.write_index.load(Ordering::Acquire)
     }
+    /// Reads write index between two reads of read index and retries until read index remains unchanged.
+    ///
+    /// So the returned pair was actual at the moment write index was loaded.
+    fn indices(&self) -> (usize, usize) {
+        let mut read = self.read_index();
+        loop {
+            let write = self.write_index();
+            let read_after = self.read_index();
+            if read == read_after {
+                break (read, write);
+            }
+            read = read_after;
+        }
+    }
 
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
-        let (first, second) = ranges(self.capacity(), start, end);
+        let (first, second) = if S::MIRRORED {
+            ranges_mirrored(self.capacity(), start, end)
+        } else {
+            ranges(self.capacity(), start, end)
+        };
         (self.storage.slice(first), self.storage.slice(second))
     }
+    #[inline]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<S::Item>], &mut [MaybeUninit<S::Item>]) {
-        let (first, second) = ranges(self.capacity(), start, end);
+        let (first, second) = if S::MIRRORED {
+            ranges_mirrored(self.capacity(), start, end)
+        } else {
+            ranges(self.capacity(), start, end)
+        };
         (self.storage.slice_mut(first), self.storage.slice_mut(second))
     }
 
@@ -117,23 +269,50 @@ impl<S: Storage + ?Sized> Observer for SharedRb<S> {
     fn write_is_held(&self) -> bool {
         self.write_held.load(Ordering::Acquire)
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        S::MIRRORED
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.total_written.load(Ordering::Relaxed)
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.total_read.load(Ordering::Relaxed)
+    }
 }
 
-impl<S: Storage + ?Sized> Producer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Producer for SharedRb<S, I> {
     #[inline]
     unsafe fn set_write_index(&self, value: usize) {
         self.write_index.store(value, Ordering::Release);
     }
+    #[inline]
+    unsafe fn advance_write_index(&self, count: usize) {
+        #[cfg(feature = "metrics")]
+        self.total_written.fetch_add(count as u64, Ordering::Relaxed);
+        self.set_write_index((self.write_index() + count) % (2 * self.capacity().get()));
+    }
 }
 
-impl<S: Storage + ?Sized> Consumer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Consumer for SharedRb<S, I> {
     #[inline]
     unsafe fn set_read_index(&self, value: usize) {
         self.read_index.store(value, Ordering::Release);
     }
+    #[inline]
+    unsafe fn advance_read_index(&self, count: usize) {
+        #[cfg(feature = "metrics")]
+        self.total_read.fetch_add(count as u64, Ordering::Relaxed);
+        self.set_read_index((self.read_index() + count) % (2 * self.capacity().get()));
+    }
 }
 
-impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> RingBuffer for SharedRb<S, I> {
     #[inline]
     unsafe fn hold_read(&self, flag: bool) -> bool {
         self.read_held.swap(flag, Ordering::AcqRel)
@@ -142,16 +321,21 @@ impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.write_held.swap(flag, Ordering::AcqRel)
     }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.total_read.fetch_add(count as u64, Ordering::Relaxed);
+    }
 }
 
-impl<S: Storage + ?Sized> Drop for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Drop for SharedRb<S, I> {
     fn drop(&mut self) {
         self.clear();
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<S: Storage> Split for SharedRb<S> {
+impl<S: Storage, I: AtomicIndex> Split for SharedRb<S, I> {
     type Prod = CachingProd<Arc<Self>>;
     type Cons = CachingCons<Arc<Self>>;
 
@@ -160,7 +344,110 @@ impl<S: Storage> Split for SharedRb<S> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
+impl<S: Storage, I: AtomicIndex> SharedRb<S, I> {
+    /// Reconstructs the ring buffer from producer and consumer returned by [`Split::split`] preserving its items.
+    ///
+    /// Succeeds only if both halves refer to the same ring buffer and there are no other strong references to it.
+    /// Otherwise the halves are returned back.
+    pub fn rejoin(
+        prod: CachingProd<Arc<Self>>,
+        cons: CachingCons<Arc<Self>>,
+    ) -> Result<Self, (CachingProd<Arc<Self>>, CachingCons<Arc<Self>>)> {
+        if !Arc::ptr_eq(prod.rb_ref(), cons.rb_ref()) || Arc::strong_count(prod.rb_ref()) != 2 {
+            return Err((prod, cons));
+        }
+        drop(cons.into_rb_ref());
+        // Strong count may have been increased by upgrading a weak observer in the meantime.
+        Arc::try_unwrap(prod.into_rb_ref()).map_err(|rb| (CachingProd::new(rb.clone()), CachingCons::new(rb)))
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage, I: AtomicIndex> SharedRb<S, I> {
+    /// Splits the ring buffer into producer and consumer sharing a shutdown flag.
+    ///
+    /// The flag is initially unset and can be set by either half via [`Stoppable::stop`] or directly through the returned [`Arc`].
+    #[allow(clippy::type_complexity)]
+    pub fn split_with_shutdown(
+        self,
+    ) -> (
+        Stoppable<CachingProd<Arc<Self>>>,
+        Stoppable<CachingCons<Arc<Self>>>,
+        Arc<AtomicBool>,
+    ) {
+        let (prod, cons) = self.split();
+        let flag = Arc::new(AtomicBool::new(false));
+        (
+            Stoppable {
+                base: prod,
+                flag: flag.clone(),
+            },
+            Stoppable {
+                base: cons,
+                flag: flag.clone(),
+            },
+            flag,
+        )
+    }
+}
+
+/// Producer or consumer sharing a shutdown flag with its counterpart.
+///
+/// Created by [`SharedRb::split_with_shutdown`].
+#[cfg(feature = "alloc")]
+pub struct Stoppable<B: Observer> {
+    base: B,
+    flag: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Observer> Stoppable<B> {
+    /// Whether the shutdown flag is set.
+    pub fn should_stop(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+    /// Set the shutdown flag.
+    pub fn stop(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+
+    /// Get the underlying wrapper.
+    pub fn into_inner(self) -> B {
+        self.base
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Observer> Based for Stoppable<B> {
+    type Base = B;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Observer> DelegateObserver for Stoppable<B> {}
+#[cfg(feature = "alloc")]
+impl<B: Producer> DelegateProducer for Stoppable<B> {}
+#[cfg(feature = "alloc")]
+impl<B: Consumer> DelegateConsumer for Stoppable<B> {}
+
+#[cfg(feature = "alloc")]
+impl<B: Observer> AsRef<Self> for Stoppable<B> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<B: Observer> AsMut<Self> for Stoppable<B> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage + ?Sized, I: AtomicIndex> Split for Arc<SharedRb<S, I>> {
     type Prod = CachingProd<Self>;
     type Cons = CachingCons<Self>;
 
@@ -169,15 +456,15 @@ impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Box<SharedRb<S>> {
-    type Prod = CachingProd<Arc<SharedRb<S>>>;
-    type Cons = CachingCons<Arc<SharedRb<S>>>;
+impl<S: Storage + ?Sized, I: AtomicIndex> Split for Box<SharedRb<S, I>> {
+    type Prod = CachingProd<Arc<SharedRb<S, I>>>;
+    type Cons = CachingCons<Arc<SharedRb<S, I>>>;
 
     fn split(self) -> (Self::Prod, Self::Cons) {
-        Arc::<SharedRb<S>>::from(self).split()
+        Arc::<SharedRb<S, I>>::from(self).split()
     }
 }
-impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> SplitRef for SharedRb<S, I> {
     type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
     type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
 
@@ -186,17 +473,18 @@ impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
     }
 }
 
-rb_impl_init!(SharedRb);
+rb_impl_init!(SharedRb, I: AtomicIndex);
 
-impl_producer_traits!(SharedRb<S: Storage>);
-impl_consumer_traits!(SharedRb<S: Storage>);
+impl_observer_traits!([S: Storage + ?Sized, I: AtomicIndex] SharedRb<S, I>);
+impl_producer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
+impl_consumer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
 
-impl<S: Storage + ?Sized> AsRef<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> AsRef<Self> for SharedRb<S, I> {
     fn as_ref(&self) -> &Self {
         self
     }
 }
-impl<S: Storage + ?Sized> AsMut<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> AsMut<Self> for SharedRb<S, I> {
     fn as_mut(&mut self) -> &mut Self {
         self
     }
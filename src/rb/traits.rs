@@ -11,12 +11,13 @@ pub unsafe trait RbRef: Clone + AsRef<Self::Rb> {
     /// Underlying ring buffer.
     type Rb: RingBuffer + ?Sized;
     /// Get ring buffer reference.
+    #[inline]
     fn rb(&self) -> &Self::Rb {
         self.as_ref()
     }
 }
 
-unsafe impl<'a, B: RingBuffer + AsRef<B> + ?Sized> RbRef for &'a B {
+unsafe impl<B: RingBuffer + AsRef<B> + ?Sized> RbRef for &B {
     type Rb = B;
 }
 #[cfg(feature = "alloc")]
@@ -6,6 +6,7 @@ use core::{num::NonZeroUsize, ops::Range};
 /// Actual indices are taken modulo `capacity`.
 ///
 /// The first range starts from `start`. If the first slice is empty then second slice is empty too.
+#[inline]
 pub fn ranges(capacity: NonZeroUsize, start: usize, end: usize) -> (Range<usize>, Range<usize>) {
     let (head_quo, head_rem) = (start / capacity, start % capacity);
     let (tail_quo, tail_rem) = (end / capacity, end % capacity);
@@ -16,3 +17,14 @@ pub fn ranges(capacity: NonZeroUsize, start: usize, end: usize) -> (Range<usize>
         (head_rem..capacity.get(), 0..tail_rem)
     }
 }
+
+/// Same as [`ranges`] but for mirrored storage (see [`Storage::MIRRORED`](`crate::storage::Storage::MIRRORED`)).
+///
+/// The second range is always empty, the first one may span beyond `capacity`.
+#[inline]
+pub fn ranges_mirrored(capacity: NonZeroUsize, start: usize, end: usize) -> (Range<usize>, Range<usize>) {
+    let head = start % capacity;
+    let modulus = 2 * capacity.get();
+    let len = (end % modulus + modulus - start % modulus) % modulus;
+    (head..(head + len), 0..0)
+}
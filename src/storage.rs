@@ -1,8 +1,17 @@
+use crate::rb::AtomicIndex;
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
-use core::{cell::UnsafeCell, marker::PhantomData, mem::MaybeUninit, ops::Range, ptr::NonNull, slice};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicUsize;
 #[cfg(feature = "alloc")]
-use core::{mem::ManuallyDrop, ptr};
+use core::{
+    alloc::Layout,
+    mem::{self, ManuallyDrop},
+    ptr,
+};
+use core::{cell::UnsafeCell, marker::PhantomData, mem::MaybeUninit, ops::Range, ptr::NonNull, slice};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicUsize;
 
 /// Abstract storage for the ring buffer.
 ///
@@ -26,6 +35,11 @@ pub unsafe trait Storage {
         self.len() == 0
     }
 
+    /// Whether storage memory is mapped twice in a row, so that slot at `index + len()` is the same as slot at `index`.
+    ///
+    /// If `true` then ring buffer never splits occupied or vacant memory into two slices.
+    const MIRRORED: bool = false;
+
     /// Return pointer to the beginning of the storage items.
     fn as_ptr(&self) -> *const MaybeUninit<Self::Item> {
         self.as_mut_ptr().cast_const()
@@ -99,6 +113,26 @@ impl<T> From<T> for Owning<T> {
     }
 }
 
+/// Maximum capacity of a ring buffer.
+///
+/// Read and write indices are stored as `usize` modulo `2 * capacity`.
+/// Index arithmetic operates on values up to `4 * capacity`, so it must fit into `usize`.
+///
+/// This mostly matters for zero-sized items, because such ring buffers of any capacity don't allocate memory.
+pub const MAX_CAPACITY: usize = usize::MAX / 4;
+
+/// Compile-time validation of static capacity `N` for ring buffer with indices stored in `I`.
+///
+/// Ring buffers with `usize` indices use the default `I`.
+pub(crate) struct StaticCapacity<const N: usize, I: AtomicIndex = AtomicUsize>(PhantomData<I>);
+impl<const N: usize, I: AtomicIndex> StaticCapacity<N, I> {
+    pub(crate) const VALID: () = {
+        assert!(N > 0, "Capacity must be greater than zero");
+        assert!(N <= MAX_CAPACITY, "Capacity is too large for `usize` indices");
+        assert!(N <= I::MAX / 2, "Capacity is too large for index type");
+    };
+}
+
 pub type Array<T, const N: usize> = Owning<[MaybeUninit<T>; N]>;
 unsafe impl<T, const N: usize> Storage for Array<T, N> {
     type Item = T;
@@ -117,6 +151,66 @@ impl<T, const N: usize> From<Array<T, N>> for [MaybeUninit<T>; N] {
     }
 }
 
+/// Marker types specifying alignment of [`AlignedArray`] storage.
+pub mod align {
+    macro_rules! align_types {
+        ($($name:ident = $value:literal),+ $(,)?) => {
+            $(
+                #[doc = concat!("Alignment of ", stringify!($value), " bytes.")]
+                #[derive(Clone, Copy, Debug, Default)]
+                #[repr(align($value))]
+                pub struct $name;
+            )+
+        };
+    }
+
+    align_types!(
+        Align8 = 8,
+        Align16 = 16,
+        Align32 = 32,
+        Align64 = 64,
+        Align128 = 128,
+        Align256 = 256,
+        Align512 = 512,
+        Align1024 = 1024,
+        Align4096 = 4096,
+    );
+}
+
+/// Array storage which beginning is aligned at least as `A`.
+///
+/// Alignment is specified by marker type from [`align`] module, e.g. `AlignedArray<u8, 256, align::Align64>`.
+/// Useful when storage is shared with a DMA engine or other hardware requiring specific alignment.
+pub struct AlignedArray<T, const N: usize, A> {
+    _align: [A; 0],
+    data: UnsafeCell<[MaybeUninit<T>; N]>,
+}
+unsafe impl<T, const N: usize, A> Sync for AlignedArray<T, N, A> where T: Send {}
+impl<T, const N: usize, A> From<[MaybeUninit<T>; N]> for AlignedArray<T, N, A> {
+    fn from(value: [MaybeUninit<T>; N]) -> Self {
+        Self {
+            _align: [],
+            data: UnsafeCell::new(value),
+        }
+    }
+}
+unsafe impl<T, const N: usize, A> Storage for AlignedArray<T, N, A> {
+    type Item = T;
+    #[inline]
+    fn as_mut_ptr(&self) -> *mut MaybeUninit<T> {
+        self.data.get().cast()
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize, A> From<AlignedArray<T, N, A>> for [MaybeUninit<T>; N] {
+    fn from(value: AlignedArray<T, N, A>) -> Self {
+        value.data.into_inner()
+    }
+}
+
 pub type Slice<T> = Owning<[MaybeUninit<T>]>;
 unsafe impl<T> Storage for Slice<T> {
     type Item = T;
@@ -134,6 +228,8 @@ unsafe impl<T> Storage for Slice<T> {
 pub struct Heap<T> {
     ptr: *mut MaybeUninit<T>,
     len: usize,
+    /// Alignment of allocated memory. Equals to `align_of::<T>()` if memory is allocated as boxed slice.
+    align: usize,
 }
 #[cfg(feature = "alloc")]
 unsafe impl<T> Send for Heap<T> where T: Send {}
@@ -154,6 +250,7 @@ unsafe impl<T> Storage for Heap<T> {
 #[cfg(feature = "alloc")]
 impl<T> Heap<T> {
     /// Create a new heap storage with exact capacity.
+    #[track_caller]
     pub fn new(capacity: usize) -> Self {
         let mut data = Vec::<MaybeUninit<T>>::with_capacity(capacity);
         // `data.capacity()` is not guaranteed to be equal to `capacity`.
@@ -161,6 +258,39 @@ impl<T> Heap<T> {
         unsafe { data.set_len(capacity) };
         Self::from(data.into_boxed_slice())
     }
+
+    /// Create a new heap storage with exact capacity which beginning is aligned at least to `align` bytes.
+    ///
+    /// *Panics if `align` is not a power of two or if allocation size overflows.*
+    #[track_caller]
+    pub fn new_aligned(capacity: usize, align: usize) -> Self {
+        let layout = Self::layout(capacity, align);
+        let ptr = if layout.size() == 0 {
+            // Dangling but properly aligned pointer.
+            ptr::null_mut::<u8>().wrapping_add(layout.align())
+        } else {
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(layout);
+            }
+            ptr
+        };
+        Self {
+            ptr: ptr.cast(),
+            len: capacity,
+            align: layout.align(),
+        }
+    }
+
+    #[track_caller]
+    fn layout(capacity: usize, align: usize) -> Layout {
+        Layout::array::<T>(capacity)
+            .and_then(|layout| layout.align_to(align))
+            .expect("Invalid layout")
+    }
+    fn is_boxed(&self) -> bool {
+        self.align == mem::align_of::<T>()
+    }
 }
 #[cfg(feature = "alloc")]
 impl<T> From<Vec<MaybeUninit<T>>> for Heap<T> {
@@ -180,20 +310,167 @@ impl<T> From<Box<[MaybeUninit<T>]>> for Heap<T> {
         Self {
             len: value.len(),
             ptr: Box::into_raw(value).cast(),
+            align: mem::align_of::<T>(),
         }
     }
 }
 #[cfg(feature = "alloc")]
 impl<T> From<Heap<T>> for Box<[MaybeUninit<T>]> {
     fn from(value: Heap<T>) -> Self {
-        let value = ManuallyDrop::new(value);
-        unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(value.ptr, value.len)) }
+        if value.is_boxed() {
+            let value = ManuallyDrop::new(value);
+            unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(value.ptr, value.len)) }
+        } else {
+            // Memory allocated with custom alignment cannot be passed to `Box`, so contents are copied.
+            let mut data = Vec::<MaybeUninit<T>>::with_capacity(value.len);
+            unsafe {
+                ptr::copy_nonoverlapping(value.ptr, data.as_mut_ptr(), value.len);
+                data.set_len(value.len);
+            }
+            data.into_boxed_slice()
+        }
     }
 }
 #[cfg(feature = "alloc")]
 impl<T> Drop for Heap<T> {
     fn drop(&mut self) {
-        drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(self.ptr, self.len)) });
+        if self.is_boxed() {
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(self.ptr, self.len)) });
+        } else {
+            let layout = Self::layout(self.len, self.align);
+            if layout.size() != 0 {
+                unsafe { alloc::alloc::dealloc(self.ptr.cast(), layout) };
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "mmap", any(target_os = "linux", target_os = "android")))]
+pub use mirrored::MirroredHeap;
+
+#[cfg(all(feature = "mmap", any(target_os = "linux", target_os = "android")))]
+mod mirrored {
+    use super::Storage;
+    use core::{marker::PhantomData, mem::MaybeUninit, ptr};
+    use std::io;
+
+    /// Heap storage which memory pages are mapped twice consecutively in virtual memory.
+    ///
+    /// Any window of at most `len()` slots starting inside of the storage is contiguous in memory,
+    /// so ring buffer slices never wrap (see [`Storage::MIRRORED`]).
+    pub struct MirroredHeap<T> {
+        ptr: *mut MaybeUninit<T>,
+        len: usize,
+        /// Size of a single mapping in bytes.
+        size: usize,
+        _ghost: PhantomData<T>,
+    }
+    unsafe impl<T> Send for MirroredHeap<T> where T: Send {}
+    unsafe impl<T> Sync for MirroredHeap<T> where T: Send {}
+    unsafe impl<T> Storage for MirroredHeap<T> {
+        type Item = T;
+        const MIRRORED: bool = true;
+
+        #[inline]
+        fn as_mut_ptr(&self) -> *mut MaybeUninit<T> {
+            self.ptr
+        }
+        #[inline]
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    fn check(ret: libc::c_int) -> io::Result<libc::c_int> {
+        if ret < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    impl<T> MirroredHeap<T> {
+        /// Create a new mirrored storage with capacity of at least `capacity` items.
+        ///
+        /// Capacity is rounded up so that the storage occupies a whole number of memory pages.
+        ///
+        /// *Panics if `capacity` is zero, if `T` is zero-sized or if size overflows.*
+        #[track_caller]
+        pub fn new(capacity: usize) -> io::Result<Self> {
+            assert!(capacity > 0, "Capacity must be greater than zero");
+            let item_size = core::mem::size_of::<T>();
+            assert!(item_size > 0, "Zero-sized items are not supported");
+            let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+            assert!(page_size.is_power_of_two() && page_size.is_multiple_of(core::mem::align_of::<T>()));
+
+            // Mapping size must be a multiple of both page size and item size, so that the mirror starts at item boundary.
+            let unit = page_size / gcd(page_size, item_size) * item_size;
+            let size = capacity
+                .checked_mul(item_size)
+                .and_then(|size| size.checked_next_multiple_of(unit))
+                .filter(|size| *size <= isize::MAX as usize / 2)
+                .expect("Storage size overflow");
+
+            unsafe {
+                let fd = check(libc::memfd_create(c"ringbuf".as_ptr(), libc::MFD_CLOEXEC))?;
+                let res = Self::map(fd, size);
+                libc::close(fd);
+                let ptr = res?;
+                Ok(Self {
+                    ptr: ptr.cast(),
+                    len: size / item_size,
+                    size,
+                    _ghost: PhantomData,
+                })
+            }
+        }
+
+        unsafe fn map(fd: libc::c_int, size: usize) -> io::Result<*mut libc::c_void> {
+            check(libc::ftruncate(fd, size as libc::off_t))?;
+            // Reserve address space for both mappings.
+            let ptr = libc::mmap(
+                ptr::null_mut(),
+                2 * size,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            for half in [ptr, ptr.byte_add(size)] {
+                let res = libc::mmap(
+                    half,
+                    size,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED | libc::MAP_FIXED,
+                    fd,
+                    0,
+                );
+                if res == libc::MAP_FAILED {
+                    let err = io::Error::last_os_error();
+                    libc::munmap(ptr, 2 * size);
+                    return Err(err);
+                }
+            }
+            Ok(ptr)
+        }
+    }
+
+    impl<T> Drop for MirroredHeap<T> {
+        fn drop(&mut self) {
+            // Unmaps both halves at once.
+            unsafe { libc::munmap(self.ptr.cast(), 2 * self.size) };
+        }
     }
 }
 
@@ -208,7 +485,10 @@ mod tests {
     fn check_send_sync() {
         let _: Check<Ref<Cell<i32>>>;
         let _: Check<Array<Cell<i32>, 4>>;
+        let _: Check<AlignedArray<Cell<i32>, 4, align::Align64>>;
         let _: Check<Slice<Cell<i32>>>;
         let _: Check<Heap<Cell<i32>>>;
+        #[cfg(all(feature = "mmap", any(target_os = "linux", target_os = "android")))]
+        let _: Check<MirroredHeap<Cell<i32>>>;
     }
 }
@@ -244,3 +244,79 @@ fn push_pop() {
     }
     assert_eq!(prod.occupied_len(), 0);
 }
+
+#[test]
+fn borrow() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert!(cons.borrow().is_empty());
+
+    prod.push_slice(&[0, 1, 2]);
+    cons.skip(2);
+    prod.push_slice(&[3, 4, 5]);
+
+    let view = cons.borrow();
+    assert_eq!(view.len(), 4);
+    assert_eq!(view.as_slices(), (&[2, 3][..], &[4, 5][..]));
+    assert_eq!((view[0], view[1], view[2], view[3]), (2, 3, 4, 5));
+    assert_eq!(view.get(4), None);
+    assert!(view.into_iter().copied().eq(2..6));
+
+    cons.try_pop();
+    let view = cons.borrow();
+    prod.try_push(6).unwrap();
+    // Items pushed after view creation aren't visible.
+    assert!(view.iter().copied().eq(3..6));
+    assert!(cons.borrow().iter().copied().eq(3..7));
+}
+
+#[test]
+fn snapshot_options() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    assert_eq!(rb.snapshot_options::<3>(), [None, None, None]);
+
+    rb.push_iter(0..4);
+    rb.skip(2);
+    rb.push_iter(4..5);
+    assert_eq!(rb.snapshot_options::<5>(), [Some(2), Some(3), Some(4), None, None]);
+    assert_eq!(rb.snapshot_options::<2>(), [Some(2), Some(3)]);
+    assert_eq!(rb.occupied_len(), 3);
+}
+
+#[test]
+fn peek_at() {
+    const CAP: usize = 5;
+    for offset in 0..CAP {
+        for len in 0..=CAP {
+            let mut rb = Rb::<Array<usize, CAP>>::default();
+            let (mut prod, mut cons) = rb.split_ref();
+            prod.push_iter(0..offset);
+            cons.skip(offset);
+            prod.push_iter(0..len);
+
+            for i in 0..len {
+                assert_eq!(cons.peek_at(i), Some(&i));
+                *cons.peek_at_mut(i).unwrap() *= 10;
+            }
+            assert_eq!(cons.peek_at(len), None);
+            assert_eq!(cons.peek_at_mut(len), None);
+            assert!(cons.iter().copied().eq((0..len).map(|i| 10 * i)));
+        }
+    }
+}
+
+#[test]
+fn peek_two() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    assert_eq!(rb.peek_two(), (None, None));
+    rb.try_push(1).unwrap();
+    assert_eq!(rb.peek_two(), (Some(&1), None));
+    rb.push_iter(2..4);
+    assert_eq!(rb.peek_two(), (Some(&1), Some(&2)));
+
+    rb.skip(2);
+    rb.try_push(4).unwrap();
+    assert_eq!(rb.as_slices(), (&[3][..], &[4][..]));
+    assert_eq!(rb.peek_two(), (Some(&3), Some(&4)));
+}
@@ -0,0 +1,62 @@
+use super::Rb;
+use crate::{
+    storage::{align::*, AlignedArray},
+    traits::*,
+};
+
+#[test]
+fn alignment() {
+    let rb = Rb::<AlignedArray<u8, 3, Align64>>::default();
+    assert_eq!(rb.occupied_slices().0.as_ptr() as usize % 64, 0);
+
+    let rb = Rb::<AlignedArray<u32, 5, Align4096>>::default();
+    let (left, right) = rb.occupied_slices();
+    assert_eq!(left.as_ptr() as usize % 4096, 0);
+    assert!(right.is_empty());
+}
+
+#[test]
+fn volatile() {
+    let mut rb = Rb::<AlignedArray<u32, 4, Align16>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice_volatile(&[0, 1, 2]), 3);
+    let mut buf = [0; 2];
+    assert_eq!(cons.pop_slice_volatile(&mut buf), 2);
+    assert_eq!(buf, [0, 1]);
+
+    assert_eq!(prod.push_slice_volatile(&[3, 4, 5, 6]), 3);
+    assert!(prod.is_full());
+
+    let mut buf = [0; 5];
+    assert_eq!(cons.pop_slice_volatile(&mut buf), 4);
+    assert_eq!(buf[..4], [2, 3, 4, 5]);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn vacant_slices_aligned() {
+    let mut rb = Rb::<AlignedArray<u32, 16, Align64>>::default();
+    rb.push_iter(0..3);
+
+    let (head, body, tail) = rb.vacant_slices_aligned_mut(16);
+    assert_eq!(head.len(), 1);
+    assert_eq!(body.as_ptr() as usize % 16, 0);
+    assert_eq!(body.len(), 12);
+    assert!(tail.is_empty());
+
+    let mut rb = Rb::<AlignedArray<u32, 16, Align64>>::default();
+    rb.push_iter(0..13);
+    rb.skip(5);
+
+    let (head, body, tail) = rb.vacant_slices_aligned_mut(8);
+    assert_eq!(head.len(), 1);
+    assert_eq!(body.as_ptr() as usize % 8, 0);
+    assert_eq!(body.len(), 2);
+    assert_eq!(tail.len(), 5);
+
+    let (head, body, tail) = rb.vacant_slices_aligned_mut(16);
+    assert_eq!(head.len(), 3);
+    assert!(body.is_empty());
+    assert_eq!(tail.len(), 5);
+}
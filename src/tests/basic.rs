@@ -21,6 +21,34 @@ fn split_capacity() {
     assert_eq!(cons.capacity().get(), CAP);
 }
 
+#[test]
+fn same_config() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let other = Rb::<Array<i32, 4>>::default();
+    let smaller = Rb::<Array<i32, 3>>::default();
+
+    assert!(rb.same_config_as(&other));
+    assert!(!rb.same_config_as(&smaller));
+
+    let (prod, _cons) = rb.split_ref();
+    assert!(prod.same_config_as(&other));
+    assert!(!smaller.same_config_as(&prod));
+}
+
+#[test]
+fn weak_observer() {
+    use crate::HeapRb;
+
+    let (prod, cons) = HeapRb::<i32>::new(2).split();
+    let weak = prod.observe().downgrade();
+    assert_eq!(weak.upgrade().unwrap().capacity().get(), 2);
+
+    drop(prod);
+    assert!(weak.upgrade().is_some());
+    drop(cons);
+    assert!(weak.upgrade().is_none());
+}
+
 #[test]
 fn try_push() {
     let mut rb = Rb::<Array<i32, 2>>::default();
@@ -165,3 +193,46 @@ fn len_remaining() {
     assert_eq!(prod.vacant_len(), 1);
     assert_eq!(cons.vacant_len(), 1);
 }
+
+#[test]
+fn try_push_spin() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push_spin(123, 0), Ok(()));
+    assert_eq!(prod.try_push_spin(234, 16), Ok(()));
+    assert_eq!(prod.try_push_spin(345, 16), Err(345));
+    assert_eq!(prod.try_push_spin(345, 0), Err(345));
+
+    assert_eq!(cons.try_pop(), Some(123));
+    assert_eq!(prod.try_push_spin(345, 16), Ok(()));
+    assert!(cons.iter().copied().eq([234, 345]));
+}
+
+#[test]
+fn try_reserve() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    assert_eq!(rb.try_reserve(0), Ok(()));
+    assert_eq!(rb.try_reserve(4), Ok(()));
+    assert_eq!(rb.try_reserve(5), Err(4));
+
+    rb.push_iter(0..3);
+    assert_eq!(rb.try_reserve(1), Ok(()));
+    assert_eq!(rb.try_reserve(2), Err(1));
+
+    rb.try_push(3).unwrap();
+    assert_eq!(rb.try_reserve(0), Ok(()));
+    assert_eq!(rb.try_reserve(1), Err(0));
+}
+
+#[test]
+fn push_defaults() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(1..3);
+
+    assert_eq!(rb.push_defaults(1), 1);
+    assert!(rb.iter().copied().eq([1, 2, 0]));
+    assert_eq!(rb.push_defaults(3), 1);
+    assert!(rb.iter().copied().eq([1, 2, 0, 0]));
+    assert_eq!(rb.push_defaults(1), 0);
+}
@@ -0,0 +1,48 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use ::bytes::{Buf, BufMut};
+
+#[test]
+fn wrapped() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    prod.push_slice(&[0; 5]);
+    cons.skip(5);
+
+    let msg = b"abcdefg";
+    assert_eq!(prod.remaining_mut(), 8);
+    assert_eq!(prod.chunk_mut().len(), 3);
+    prod.put_slice(msg);
+    assert_eq!(prod.remaining_mut(), 1);
+    assert_eq!(cons.as_slices(), (&b"abc"[..], &b"defg"[..]));
+
+    assert_eq!(cons.remaining(), 7);
+    assert_eq!(cons.chunk(), b"abc");
+    let mut buf = [0; 7];
+    cons.copy_to_slice(&mut buf);
+    assert_eq!(&buf, msg);
+    assert_eq!(cons.remaining(), 0);
+    assert!(cons.chunk().is_empty());
+}
+
+#[test]
+fn chain() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    prod.push_slice(&[0; 3]);
+    cons.skip(3);
+
+    prod.put_u16(0x0102);
+    prod.put_u8(3);
+    assert_eq!(cons.get_u8(), 1);
+    assert_eq!(cons.get_u16(), 0x0203);
+}
+
+#[test]
+#[should_panic]
+fn advance_past_remaining() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    prod.push_slice(&[1, 2]);
+    cons.advance(3);
+}
@@ -0,0 +1,187 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+
+fn indices(this: &impl Observer) -> (usize, usize) {
+    (this.read_index(), this.write_index())
+}
+
+#[test]
+fn normalize_empty() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..4);
+    rb.clear();
+    assert_eq!(indices(&rb), (4, 4));
+
+    rb.normalize();
+    assert_eq!(indices(&rb), (0, 0));
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn normalize_wrapped() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.skip(3);
+    rb.push_iter(3..6);
+    assert_eq!(rb.as_slices(), (&[3][..], &[4, 5][..]));
+
+    rb.normalize();
+    assert_eq!(indices(&rb), (0, 3));
+    assert_eq!(rb.as_slices(), (&[3, 4, 5][..], &[][..]));
+
+    rb.push_iter(6..7);
+    assert!(rb.iter().copied().eq(3..7));
+}
+
+#[test]
+fn normalize_full() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..2);
+    rb.skip(2);
+    rb.push_iter(2..6);
+    assert!(rb.is_full());
+
+    rb.normalize();
+    assert_eq!(indices(&rb), (0, 4));
+    assert_eq!(rb.as_slices(), (&[2, 3, 4, 5][..], &[][..]));
+}
+
+#[test]
+fn extend_from_within() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..4);
+
+    rb.extend_from_within(1..3);
+    assert!(rb.iter().copied().eq([0, 1, 2, 3, 1, 2]));
+
+    rb.extend_from_within(0..4);
+    assert!(rb.iter().copied().eq([0, 1, 2, 3, 1, 2, 0, 1]));
+    assert!(rb.is_full());
+}
+
+#[test]
+fn extend_from_within_wrapped() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..8);
+    rb.skip(5);
+    rb.push_iter(8..10);
+    assert_eq!(rb.occupied_slices().0.len(), 3);
+
+    rb.extend_from_within(1..4);
+    assert!(rb.iter().copied().eq([5, 6, 7, 8, 9, 6, 7, 8]));
+}
+
+#[test]
+fn compact_if_wrapped_contiguous() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.skip(1);
+    assert_eq!(indices(&rb), (1, 3));
+
+    rb.compact_if_wrapped();
+    assert_eq!(indices(&rb), (1, 3));
+    assert_eq!(rb.as_slices(), (&[1, 2][..], &[][..]));
+}
+
+#[test]
+fn compact_if_wrapped() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..4);
+    rb.skip(3);
+    rb.push_iter(4..6);
+    assert_eq!(rb.as_slices(), (&[3][..], &[4, 5][..]));
+
+    rb.compact_if_wrapped();
+    assert_eq!(indices(&rb), (0, 3));
+    assert_eq!(rb.as_slices(), (&[3, 4, 5][..], &[][..]));
+}
+
+#[test]
+fn make_contiguous() {
+    for offset in 0..5 {
+        for len in 0..=5 {
+            let mut rb = Rb::<Array<usize, 5>>::default();
+            rb.push_iter(0..offset);
+            rb.skip(offset);
+            rb.push_iter(0..len);
+            let wrapped = !rb.as_slices().1.is_empty();
+            let before = indices(&rb);
+
+            let slice = rb.make_contiguous();
+            assert!(slice.iter().copied().eq(0..len));
+            slice.reverse();
+
+            if wrapped {
+                assert_eq!(indices(&rb), (0, len));
+            } else {
+                assert_eq!(indices(&rb), before);
+            }
+            assert!(rb.iter().copied().eq((0..len).rev()));
+        }
+    }
+}
+
+#[test]
+fn as_contiguous_slice() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    assert_eq!(rb.as_contiguous_slice(), Some(&[][..]));
+
+    rb.push_iter(0..3);
+    assert_eq!(rb.as_contiguous_slice(), Some(&[0, 1, 2][..]));
+
+    rb.skip(2);
+    rb.push_iter(3..6);
+    assert_eq!(rb.as_contiguous_slice(), None);
+
+    rb.skip(2);
+    assert_eq!(rb.as_contiguous_slice(), Some(&[4, 5][..]));
+
+    rb.clear();
+    assert_eq!(rb.as_contiguous_slice(), Some(&[][..]));
+}
+
+#[test]
+fn write_at() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..8);
+    rb.skip(5);
+    rb.push_iter(8..11);
+    assert_eq!(rb.as_slices(), (&[5, 6, 7][..], &[8, 9, 10][..]));
+
+    assert!(rb.write_at(1, &[16]));
+    assert!(rb.iter().copied().eq([5, 16, 7, 8, 9, 10]));
+
+    assert!(rb.write_at(2, &[27, 28, 29]));
+    assert!(rb.iter().copied().eq([5, 16, 27, 28, 29, 10]));
+
+    assert!(rb.write_at(4, &[39, 310]));
+    assert!(rb.write_at(6, &[]));
+    assert!(rb.iter().copied().eq([5, 16, 27, 28, 39, 310]));
+
+    assert!(!rb.write_at(5, &[0, 0]));
+    assert!(!rb.write_at(7, &[]));
+    assert!(!rb.write_at(usize::MAX, &[0]));
+    assert!(rb.iter().copied().eq([5, 16, 27, 28, 39, 310]));
+}
+
+#[test]
+fn len_contiguous() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    for offset in 0..8 {
+        for len in 0..=4 {
+            rb.clear();
+            rb.push_iter(0..offset);
+            rb.skip(offset as usize);
+            rb.push_iter(0..len);
+            assert_eq!(rb.occupied_len_contiguous(), rb.occupied_slices().0.len());
+            assert_eq!(rb.vacant_len_contiguous(), rb.vacant_slices().0.len());
+        }
+    }
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.skip(3);
+    rb.push_iter(0..2);
+    assert_eq!(rb.occupied_len_contiguous(), 1);
+    assert_eq!(rb.vacant_len_contiguous(), 2);
+}
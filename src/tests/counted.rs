@@ -0,0 +1,55 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, wrap::Counted};
+
+#[test]
+fn totals() {
+    let mut rb = Counted::new(Rb::<Array<i32, 4>>::default());
+
+    rb.try_push(0).unwrap();
+    assert_eq!(rb.push_slice(&[1, 2, 3, 4]), 3);
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (4, 0));
+
+    assert_eq!(rb.try_pop(), Some(0));
+    assert_eq!(rb.skip(2), 2);
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (4, 3));
+
+    // Overwrites pop the eldest items.
+    rb.push_iter_overwrite(5..10);
+    assert!(rb.iter().copied().eq(6..10));
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (9, 5));
+    assert_eq!(rb.push_overwrite(10), Some(6));
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (10, 6));
+
+    assert_eq!(rb.clear(), 4);
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (10, 10));
+
+    rb.reset_totals();
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (0, 0));
+}
+
+#[test]
+fn split() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut cons) = (Counted::new(prod), Counted::new(cons));
+
+    for i in 0..10 {
+        prod.try_push(i).unwrap();
+        assert_eq!(cons.try_pop(), Some(i));
+    }
+    assert_eq!(prod.total_pushed(), 10);
+    assert_eq!(cons.total_popped(), 10);
+    assert_eq!(prod.into_inner().occupied_len(), 0);
+}
+
+#[test]
+fn removal_from_middle() {
+    let mut rb = Counted::new(Rb::<Array<i32, 8>>::default());
+    rb.push_iter(0..8);
+
+    rb.retain(|x| x % 2 == 0);
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (8, 4));
+    assert!(rb.drain(1..3).eq([2, 4]));
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (8, 6));
+    assert_eq!(rb.total_pushed() - rb.total_popped(), rb.occupied_len() as u64);
+}
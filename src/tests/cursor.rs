@@ -0,0 +1,78 @@
+use super::{
+    drop::{ids, wrapped, Dropper},
+    Rb,
+};
+use crate::{storage::Array, traits::*};
+use alloc::collections::BTreeSet;
+use core::cell::RefCell;
+
+fn remove(rb: &mut Rb<Array<Dropper<'_>, 8>>, index: usize) -> i32 {
+    let mut cursor = rb.cursor_mut();
+    for _ in 0..index {
+        assert!(cursor.advance());
+    }
+    assert_eq!(cursor.index(), index);
+    let id = cursor.remove_current().unwrap().id;
+    assert_eq!(cursor.index(), index);
+    id
+}
+
+#[test]
+fn front() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    assert_eq!(remove(&mut rb, 0), 0);
+    assert_eq!(ids(&rb), [1, 2, 3, 4, 5]);
+    assert_eq!(set.borrow().len(), 5);
+}
+
+#[test]
+fn middle() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    assert_eq!(remove(&mut rb, 2), 2);
+    assert_eq!(ids(&rb), [0, 1, 3, 4, 5]);
+    assert_eq!(remove(&mut rb, 3), 4);
+    assert_eq!(ids(&rb), [0, 1, 3, 5]);
+    assert_eq!(set.borrow().len(), 4);
+
+    rb.push_iter((6..10).map(|i| Dropper::new(&set, i)));
+    assert_eq!(ids(&rb), [0, 1, 3, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn back() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    assert_eq!(remove(&mut rb, 5), 5);
+    assert_eq!(ids(&rb), [0, 1, 2, 3, 4]);
+
+    let mut cursor = rb.cursor_mut();
+    for _ in 0..5 {
+        cursor.advance();
+    }
+    assert!(cursor.current().is_none());
+    assert!(cursor.remove_current().is_none());
+    assert!(!cursor.advance());
+    assert_eq!(set.borrow().len(), 5);
+}
+
+#[test]
+fn filter() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    let mut cursor = rb.cursor_mut();
+    while let Some(d) = cursor.current() {
+        if d.id % 3 == 0 {
+            cursor.remove_current();
+        } else {
+            cursor.advance();
+        }
+    }
+    assert_eq!(ids(&rb), [1, 2, 4, 5]);
+    assert_eq!(*set.borrow(), BTreeSet::from([1, 2, 4, 5]));
+}
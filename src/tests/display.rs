@@ -0,0 +1,66 @@
+use super::Rb;
+use crate::{
+    storage::Array,
+    traits::*,
+    wrap::{CachingCons, CachingProd, Cons, Prod},
+};
+use alloc::format;
+
+#[test]
+fn display() {
+    let mut rb = Rb::<Array<i32, 256>>::default();
+    rb.push_iter(0..3);
+    assert_eq!(format!("{}", rb), "RingBuffer(3/256)");
+
+    {
+        let (mut prod, cons) = (Prod::new(&rb), Cons::new(&rb));
+        assert_eq!(format!("{}", prod), "RingBuffer(3/256)");
+        prod.try_push(3).unwrap();
+        assert_eq!(format!("{}", cons), "RingBuffer(4/256)");
+        assert_eq!(format!("{}", cons.observe()), "RingBuffer(4/256)");
+    }
+    {
+        let (prod, mut cons) = (CachingProd::new(&rb), CachingCons::new(&rb));
+        assert_eq!(format!("{}", cons), "RingBuffer(4/256)");
+        cons.skip(4);
+        assert_eq!(format!("{}", prod), "RingBuffer(0/256)");
+    }
+}
+
+#[test]
+fn debug() {
+    let mut rb = crate::HeapRb::<i32>::new(8);
+    rb.push_iter(0..6);
+    rb.skip(2);
+    assert_eq!(
+        format!("{:?}", rb),
+        "RingBuffer { capacity: 8, occupied_len: 4, vacant_len: 4, items: [2, 3, 4, 5] }"
+    );
+
+    let (prod, cons) = (Prod::new(&rb), Cons::new(&rb));
+    assert_eq!(format!("{:?}", prod), "RingBuffer { capacity: 8, occupied_len: 4, vacant_len: 4 }");
+    assert_eq!(format!("{:?}", cons), format!("{:?}", rb));
+}
+
+#[test]
+fn debug_wrapped() {
+    let mut rb = crate::HeapRb::<i32>::new(4);
+    rb.push_iter(0..4);
+    rb.skip(3);
+    rb.push_iter(4..6);
+    assert_eq!(rb.as_slices(), (&[3][..], &[4, 5][..]));
+    assert!(format!("{:?}", rb).ends_with("items: [3, 4, 5] }"));
+}
+
+#[test]
+fn debug_counts() {
+    struct NotDebug;
+
+    let mut rb = crate::HeapRb::<NotDebug>::new(8);
+    rb.push_iter((0..5).map(|_| NotDebug));
+    let expected = "RingBuffer { capacity: 8, occupied_len: 5, vacant_len: 3 }";
+    assert_eq!(format!("{:?}", rb.debug_counts()), expected);
+    let (prod, cons) = (Prod::new(&rb), Cons::new(&rb));
+    assert_eq!(format!("{:?}", prod.debug_counts()), expected);
+    assert_eq!(format!("{:?}", cons.debug_counts()), expected);
+}
@@ -0,0 +1,134 @@
+use super::{
+    drop::{ids, wrapped, Dropper},
+    Rb,
+};
+use crate::{storage::Array, traits::*};
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::cell::RefCell;
+
+#[test]
+fn front() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    assert!(rb.drain(..2).map(|d| d.id).eq([0, 1]));
+    assert_eq!(ids(&rb), [2, 3, 4, 5]);
+    assert_eq!(set.borrow().len(), 4);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn middle() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    {
+        let mut drain = rb.drain(1..5);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next().map(|d| d.id), Some(1));
+        assert_eq!(drain.next_back().map(|d| d.id), Some(4));
+    }
+    assert_eq!(ids(&rb), [0, 5]);
+    assert_eq!(set.borrow().len(), 2);
+
+    rb.try_push(Dropper::new(&set, 6)).unwrap();
+    assert!(rb.drain(2..).map(|d| d.id).eq([6]));
+    assert!(rb.drain(..1).map(|d| d.id).eq([0]));
+    assert_eq!(ids(&rb), [5]);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn back() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    drop(rb.drain(3..));
+    assert_eq!(ids(&rb), [0, 1, 2]);
+    assert_eq!(set.borrow().len(), 3);
+
+    for i in 6..11 {
+        rb.try_push(Dropper::new(&set, i)).unwrap();
+    }
+    assert_eq!(ids(&rb), [0, 1, 2, 6, 7, 8, 9, 10]);
+
+    assert_eq!(rb.drain(5..=6).count(), 2);
+    assert_eq!(ids(&rb), [0, 1, 2, 6, 7, 10]);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn empty_range() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    assert_eq!(rb.drain(1..1).count(), 0);
+    assert!(rb.iter().copied().eq(0..3));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.drain(2..4);
+}
+
+#[test]
+fn take_all() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    {
+        let mut all = rb.take_all();
+        assert_eq!(all.len(), 6);
+        assert!(all.by_ref().take(2).map(|d| d.id).eq([0, 1]));
+    }
+    assert!(rb.is_empty());
+    assert!(set.borrow().is_empty());
+
+    for i in 0..8 {
+        rb.try_push(Dropper::new(&set, i)).unwrap();
+    }
+    assert!(rb.is_full());
+    assert!(rb.take_all().map(|d| d.id).eq(0..8));
+    assert!(rb.is_empty());
+    assert!(rb.take_all().next().is_none());
+}
+
+#[test]
+fn pop_find() {
+    let set = RefCell::new(BTreeSet::new());
+    let mut rb = wrapped(&set);
+
+    // Front.
+    assert_eq!(rb.pop_find(|d| d.id == 0).map(|d| d.id), Some(0));
+    assert_eq!(ids(&rb), [1, 2, 3, 4, 5]);
+    // Back.
+    assert_eq!(rb.pop_find(|d| d.id == 5).map(|d| d.id), Some(5));
+    assert_eq!(ids(&rb), [1, 2, 3, 4]);
+    // Middle, first match is taken.
+    assert_eq!(rb.pop_find(|d| d.id % 2 == 0).map(|d| d.id), Some(2));
+    assert_eq!(ids(&rb), [1, 3, 4]);
+    assert_eq!(rb.pop_find(|d| d.id > 3).map(|d| d.id), Some(4));
+    assert_eq!(ids(&rb), [1, 3]);
+    // Absent.
+    assert!(rb.pop_find(|d| d.id == 0).is_none());
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [1, 3]);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "drain range bound overflow")]
+fn bound_overflow() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.drain(1..=usize::MAX);
+}
@@ -1,16 +1,16 @@
 use super::Rb;
 use crate::{storage::Array, traits::*};
-use alloc::collections::BTreeSet;
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::cell::RefCell;
 
 #[derive(Debug)]
-struct Dropper<'a> {
-    id: i32,
+pub struct Dropper<'a> {
+    pub id: i32,
     set: &'a RefCell<BTreeSet<i32>>,
 }
 
 impl<'a> Dropper<'a> {
-    fn new(set: &'a RefCell<BTreeSet<i32>>, id: i32) -> Self {
+    pub fn new(set: &'a RefCell<BTreeSet<i32>>, id: i32) -> Self {
         if !set.borrow_mut().insert(id) {
             panic!("value {} already exists", id);
         }
@@ -26,6 +26,25 @@ impl<'a> Drop for Dropper<'a> {
     }
 }
 
+/// Ids of items in the ring buffer from front to back.
+pub fn ids<'a>(rb: &impl Consumer<Item = Dropper<'a>>) -> Vec<i32> {
+    rb.iter().map(|d| d.id).collect()
+}
+
+/// Ring buffer with items `0..6` where the occupied memory is wrapped.
+pub fn wrapped(set: &RefCell<BTreeSet<i32>>) -> Rb<Array<Dropper<'_>, 8>> {
+    let mut rb = Rb::<Array<Dropper, 8>>::default();
+    for i in 0..5 {
+        rb.try_push(Dropper::new(set, -1 - i)).unwrap();
+    }
+    rb.clear();
+    for i in 0..6 {
+        rb.try_push(Dropper::new(set, i)).unwrap();
+    }
+    assert!(!rb.as_slices().1.is_empty());
+    rb
+}
+
 #[test]
 fn single() {
     let set = RefCell::new(BTreeSet::new());
@@ -106,3 +125,274 @@ fn transaction() {
     drop(rb);
     assert_eq!(set.borrow().len(), 0);
 }
+
+#[test]
+fn push_iter_transactional() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.try_push(Dropper::new(&set, 0)).unwrap();
+    rb.try_pop().unwrap();
+
+    assert_eq!(rb.push_iter_transactional((1..6).map(|i| Dropper::new(&set, i))), Err(()));
+    assert!(rb.is_empty());
+    assert_eq!(set.borrow().len(), 0);
+
+    assert_eq!(rb.push_iter_transactional((1..4).map(|i| Dropper::new(&set, i))), Ok(3));
+    assert_eq!(rb.occupied_len(), 3);
+    assert_eq!(set.borrow().len(), 3);
+
+    assert_eq!(rb.push_iter_transactional((4..6).map(|i| Dropper::new(&set, i))), Err(()));
+    assert_eq!(rb.push_iter_transactional((4..5).map(|i| Dropper::new(&set, i))), Ok(1));
+    assert!(rb.iter().map(|d| d.id).eq(1..5));
+    assert_eq!(rb.push_iter_transactional(core::iter::empty()), Ok(0));
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn pop_uninit_exact() {
+    use core::mem::MaybeUninit;
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    for i in 0..3 {
+        rb.try_push(Dropper::new(&set, i)).unwrap();
+    }
+
+    let mut out: [MaybeUninit<Dropper>; 4] = [(); 4].map(|()| MaybeUninit::uninit());
+    assert_eq!(rb.pop_uninit_exact(&mut out), Err(3));
+    assert_eq!(rb.occupied_len(), 3);
+    assert_eq!(set.borrow().len(), 3);
+
+    assert_eq!(rb.pop_uninit_exact(&mut out[..2]), Ok(()));
+    assert_eq!(rb.occupied_len(), 1);
+    let popped = out[..2].iter_mut().map(|d| unsafe { d.assume_init_read() }).collect::<Vec<_>>();
+    assert!(popped.iter().map(|d| d.id).eq(0..2));
+    assert_eq!(set.borrow().len(), 3);
+
+    drop(popped);
+    assert_eq!(set.borrow().len(), 1);
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn push_defaults_panic() {
+    use core::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    std::thread_local! {
+        static CREATED: Cell<usize> = const { Cell::new(0) };
+        static DROPPED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct PanicOnThird;
+    impl Default for PanicOnThird {
+        fn default() -> Self {
+            let created = CREATED.get();
+            if created == 2 {
+                panic!("Third item");
+            }
+            CREATED.set(created + 1);
+            Self
+        }
+    }
+    impl Drop for PanicOnThird {
+        fn drop(&mut self) {
+            DROPPED.set(DROPPED.get() + 1);
+        }
+    }
+
+    let mut rb = Rb::<Array<PanicOnThird, 4>>::default();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| rb.push_defaults(4))).is_err());
+    assert_eq!(CREATED.get(), 2);
+    assert_eq!(DROPPED.get(), 2);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn retain() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 7>>::default();
+    rb.push_iter((0..5).map(|i| Dropper::new(&set, i)));
+    rb.skip(5);
+    rb.push_iter((5..12).map(|i| Dropper::new(&set, i)));
+    assert!(!rb.as_slices().1.is_empty());
+
+    rb.retain(|x| x.id % 2 == 0);
+    assert!(rb.iter().map(|x| x.id).eq([6, 8, 10]));
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [6, 8, 10]);
+
+    rb.push_iter((12..16).map(|i| Dropper::new(&set, i)));
+    assert!(rb.is_full());
+    assert!(rb.iter().map(|x| x.id).eq([6, 8, 10, 12, 13, 14, 15]));
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn retain_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.push_iter((0..3).map(|i| Dropper::new(&set, i)));
+    rb.skip(3);
+    rb.push_iter((3..7).map(|i| Dropper::new(&set, i)));
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        rb.retain(|x| match x.id {
+            5 => panic!("Predicate panicked"),
+            id => id % 2 != 0,
+        })
+    }));
+    assert!(res.is_err());
+    assert!(rb.iter().map(|x| x.id).eq([3, 5, 6]));
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [3, 5, 6]);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pop_each_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.push_iter((0..3).map(|i| Dropper::new(&set, i)));
+    rb.skip(3);
+    rb.push_iter((3..7).map(|i| Dropper::new(&set, i)));
+
+    let mut popped = Vec::new();
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        rb.pop_each(|x| match x.id {
+            5 => panic!("Callback panicked"),
+            id => popped.push(id),
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(popped, [3, 4]);
+    assert!(rb.iter().map(|x| x.id).eq([6]));
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [6]);
+
+    assert_eq!(rb.pop_each(|x| popped.push(x.id)), 1);
+    assert_eq!(popped, [3, 4, 6]);
+    assert!(rb.is_empty());
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn from_array() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::from_full_array(core::array::from_fn::<_, 4, _>(|i| Dropper::new(&set, i as i32)));
+    assert!(rb.is_full());
+    for i in 0..4 {
+        assert_eq!(rb.try_pop().unwrap().id, i);
+        assert_eq!(set.borrow().len(), 3 - i as usize);
+    }
+    assert!(rb.is_empty());
+
+    let rb = Rb::from_array_prefix(core::array::from_fn::<_, 4, _>(|i| Dropper::new(&set, i as i32)), 3);
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    assert!(rb.iter().map(|x| x.id).eq(0..3));
+    assert_eq!(rb.vacant_len(), 1);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn from_array_prefix_too_long() {
+    let _ = Rb::from_array_prefix([0; 4], 5);
+}
+
+#[test]
+fn resize() {
+    use crate::HeapRb;
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = HeapRb::<Dropper>::new(4);
+    rb.push_iter((0..3).map(|i| Dropper::new(&set, i)));
+    rb.skip(3);
+    rb.push_iter((3..7).map(|i| Dropper::new(&set, i)));
+    assert!(!rb.as_slices().1.is_empty());
+
+    rb.resize(6);
+    assert_eq!(rb.capacity().get(), 6);
+    assert!(rb.iter().map(|x| x.id).eq(3..7));
+    rb.push_iter((7..9).map(|i| Dropper::new(&set, i)));
+    assert!(rb.is_full());
+
+    rb.resize(2);
+    assert_eq!(rb.capacity().get(), 2);
+    assert!(rb.iter().map(|x| x.id).eq(3..5));
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [3, 4]);
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn push_array() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.try_push(Dropper::new(&set, 0)).unwrap();
+
+    let items = core::array::from_fn::<_, 4, _>(|i| Dropper::new(&set, i as i32 + 1));
+    let items = rb.push_array(items).unwrap_err();
+    assert_eq!(set.borrow().len(), 5);
+    assert_eq!(rb.occupied_len(), 1);
+    drop(items);
+    assert_eq!(set.borrow().len(), 1);
+
+    let items = core::array::from_fn::<_, 3, _>(|i| Dropper::new(&set, i as i32 + 1));
+    assert!(rb.push_array(items).is_ok());
+    assert!(rb.iter().map(|x| x.id).eq(0..4));
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn expire_while() {
+    const TTL: i32 = 10;
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.try_push(Dropper::new(&set, -1)).unwrap();
+    rb.skip(1);
+    // Item ids are used as timestamps.
+    for timestamp in [0, 5, 10, 15] {
+        rb.try_push(Dropper::new(&set, timestamp)).unwrap();
+    }
+
+    let mut now = 12;
+    assert_eq!(rb.expire_while(|x| x.id + TTL <= now), 1);
+    assert!(rb.iter().map(|x| x.id).eq([5, 10, 15]));
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [5, 10, 15]);
+
+    now = 20;
+    assert_eq!(rb.expire_while(|x| x.id + TTL <= now), 2);
+    assert_eq!(rb.expire_while(|x| x.id + TTL <= now), 0);
+    assert_eq!(set.borrow().iter().copied().collect::<Vec<_>>(), [15]);
+
+    now = 30;
+    assert_eq!(rb.expire_while(|x| x.id + TTL <= now), 1);
+    assert!(rb.is_empty());
+    assert!(set.borrow().is_empty());
+}
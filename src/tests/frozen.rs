@@ -113,3 +113,44 @@ fn consumer() {
     assert_eq!(frozen_cons.occupied_len(), 1);
     assert_eq!(prod.occupied_len(), 1);
 }
+
+fn push_flush<P: Producer<Item = i32>>(prod: &mut P, items: &[i32]) {
+    prod.push_slice(items);
+    prod.flush();
+}
+
+fn pop_flush<C: Consumer<Item = i32>>(cons: &mut C) -> Option<i32> {
+    let item = cons.try_pop();
+    cons.flush();
+    item
+}
+
+#[test]
+fn generic_flush() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut frozen_cons) = (prod, cons.freeze());
+
+    // Caching producer
+    push_flush(&mut prod, &[0, 1]);
+    assert_eq!(frozen_cons.occupied_len(), 0);
+    frozen_cons.flush();
+    assert_eq!(frozen_cons.occupied_len(), 2);
+
+    // Frozen consumer
+    assert_eq!(pop_flush(&mut frozen_cons), Some(0));
+    assert_eq!(prod.vacant_len(), 3);
+
+    let mut frozen_prod = prod.freeze();
+    frozen_prod.try_push(2).unwrap();
+    assert_eq!(frozen_cons.occupied_len(), 1);
+    push_flush(&mut frozen_prod, &[3]);
+    frozen_cons.flush();
+    assert!(frozen_cons.iter().copied().eq(1..4));
+
+    // Ring buffer itself
+    drop((frozen_prod, frozen_cons));
+    push_flush(&mut rb, &[4]);
+    assert_eq!(pop_flush(&mut rb), Some(1));
+    assert!(rb.iter().copied().eq(2..5));
+}
@@ -0,0 +1,43 @@
+use crate::fuzz::{check, Input, Op};
+use alloc::vec;
+
+#[test]
+fn replay() {
+    check(&Input {
+        capacity: 2,
+        ops: vec![
+            Op::Push(1),
+            Op::PushSlice(vec![2, 3, 4]),
+            Op::Push(5),
+            Op::Pop,
+            Op::PushOverwrite(6),
+            Op::PushOverwrite(7),
+            Op::PopSlice(2),
+            Op::PushSlice(vec![8, 9]),
+            Op::Skip(usize::MAX),
+            Op::PushSlice(vec![10, 11, 12, 13]),
+            Op::PopSlice(0),
+            Op::Skip(1),
+            Op::PushOverwrite(14),
+            Op::Clear,
+            Op::Pop,
+            Op::PushOverwrite(15),
+            Op::PopSlice(u8::MAX),
+        ],
+    });
+}
+
+#[test]
+fn replay_zero_capacity_input() {
+    check(&Input {
+        capacity: 0,
+        ops: vec![
+            Op::PushOverwrite(1),
+            Op::PushOverwrite(2),
+            Op::Push(3),
+            Op::PushSlice(vec![4]),
+            Op::Pop,
+            Op::Pop,
+        ],
+    });
+}
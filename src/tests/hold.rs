@@ -42,3 +42,41 @@ fn hold_conflict() {
     let _prod = CachingProd::new(&rb);
     CachingProd::new(&rb);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn panic_location() {
+    use std::{
+        boxed::Box,
+        panic::{self, Location},
+        string::ToString,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    let location = Arc::new(Mutex::new(None));
+    let test_thread = thread::current().id();
+    let prev_hook = Arc::new(panic::take_hook());
+    panic::set_hook({
+        let location = location.clone();
+        let prev_hook = prev_hook.clone();
+        Box::new(move |info| {
+            if thread::current().id() == test_thread {
+                *location.lock().unwrap() = info.location().map(|l| (l.file().to_string(), l.line()));
+            } else {
+                prev_hook(info);
+            }
+        })
+    });
+
+    let rb = Rb::<Array<i32, 2>>::default();
+    let _prod = CachingProd::new(&rb);
+    let expected_line = Location::caller().line() + 1;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| CachingProd::new(&rb)));
+
+    drop(panic::take_hook());
+    panic::set_hook(Box::new(move |info| prev_hook(info)));
+
+    assert!(result.is_err());
+    assert_eq!(*location.lock().unwrap(), Some((file!().to_string(), expected_line)));
+}
@@ -0,0 +1,71 @@
+use crate::{
+    storage::Slice,
+    traits::*,
+    wrap::{CachingCons, CachingProd},
+    SharedRb,
+};
+
+#[repr(C, align(256))]
+struct Region([u8; 1024]);
+
+#[test]
+fn two_views() {
+    let mut region = Region([0xff; 1024]);
+    assert!(SharedRb::<Slice<u32>>::in_place_align() <= 256);
+    assert!(SharedRb::<Slice<u32>>::in_place_size(5) <= region.0.len());
+
+    let ptr = {
+        let rb = SharedRb::<Slice<u32>>::in_place(&mut region.0, 5);
+        assert_eq!(rb.capacity().get(), 5);
+        assert!(rb.is_empty());
+        rb as *mut _ as *mut u8
+    };
+    assert_eq!(ptr, region.0.as_mut_ptr());
+
+    // Each view is obtained from the base pointer only, as a separate process would do.
+    let mut prod = CachingProd::new(unsafe { SharedRb::<Slice<u32>>::attach(ptr, 5) });
+    let mut cons = CachingCons::new(unsafe { SharedRb::<Slice<u32>>::attach(ptr, 5) });
+
+    for i in 0..4 {
+        assert_eq!(prod.push_iter(10 * i..10 * i + 4), 4);
+        assert_eq!(cons.occupied_len(), 4);
+        assert!(cons.pop_iter().eq(10 * i..10 * i + 4));
+    }
+    assert_eq!(prod.push_iter(0..10), 5);
+    assert!(cons.is_full());
+}
+
+#[test]
+#[should_panic]
+fn region_too_small() {
+    let mut region = Region([0; 1024]);
+    SharedRb::<Slice<u64>>::in_place(&mut region.0, 1024);
+}
+
+#[test]
+#[should_panic]
+fn region_misaligned() {
+    let mut region = Region([0; 1024]);
+    SharedRb::<Slice<u8>>::in_place(&mut region.0[1..], 16);
+}
+
+#[test]
+#[should_panic(expected = "Region size overflow")]
+fn region_size_overflow() {
+    SharedRb::<Slice<u64>>::in_place_size(usize::MAX / 4);
+}
+
+#[test]
+#[should_panic(expected = "Region size overflow")]
+fn region_size_wrapped() {
+    let mut region = Region([0; 1024]);
+    // `item_count * size_of::<u64>()` wraps to a small size.
+    SharedRb::<Slice<u64>>::in_place(&mut region.0, usize::MAX / 8 + 2);
+}
+
+#[test]
+#[should_panic(expected = "Capacity is too large")]
+fn capacity_too_large() {
+    let mut region = Region([0; 1024]);
+    SharedRb::<Slice<u8>>::in_place(&mut region.0, crate::storage::MAX_CAPACITY + 1);
+}
@@ -77,3 +77,227 @@ fn push_pop_iter_partial() {
     assert_eq!(cons.try_pop().unwrap(), 5);
     assert!(prod.is_empty());
 }
+
+#[test]
+fn map_in_place() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0, 0]);
+    rb.skip(3);
+    rb.push_slice(&[1, 2, 3]);
+    assert_eq!(rb.as_slices(), (&[1][..], &[2, 3][..]));
+
+    rb.map_in_place(|x| *x *= 2);
+    assert!(rb.iter().copied().eq([2, 4, 6]));
+}
+
+#[test]
+fn iter_indexed() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..4);
+    rb.skip(3);
+    rb.push_iter(4..7);
+    assert_eq!(rb.occupied_slices().0.len(), 1);
+
+    assert!(rb.iter_indexed().eq([(0, &3), (1, &4), (2, &5), (3, &6)]));
+}
+
+fn check_chunks(rb: &mut impl RingBuffer<Item = i32>, n: usize, expected: &[&[i32]]) {
+    let mut expected = expected.iter();
+    rb.for_each_chunk(n, |chunk| assert_eq!(chunk, *expected.next().unwrap()));
+    assert!(expected.next().is_none());
+}
+
+#[test]
+fn for_each_chunk_wrap_inside() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..8);
+    rb.skip(6);
+    rb.push_iter(8..12);
+    assert_eq!(rb.as_slices(), (&[6, 7][..], &[8, 9, 10, 11][..]));
+
+    check_chunks(&mut rb, 3, &[&[6, 7, 8], &[9, 10, 11]]);
+    check_chunks(&mut rb, 4, &[&[6, 7, 8, 9], &[10, 11]]);
+}
+
+#[test]
+fn for_each_chunk_wrap_between() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..8);
+    rb.skip(4);
+    rb.push_iter(8..11);
+    assert_eq!(rb.as_slices(), (&[4, 5, 6, 7][..], &[8, 9, 10][..]));
+
+    check_chunks(&mut rb, 2, &[&[4, 5], &[6, 7], &[8, 9], &[10]]);
+    check_chunks(&mut rb, 4, &[&[4, 5, 6, 7], &[8, 9, 10]]);
+    // Items are not moved if no chunk crosses the end of the storage.
+    assert_eq!(rb.as_slices(), (&[4, 5, 6, 7][..], &[8, 9, 10][..]));
+}
+
+#[test]
+fn for_each_chunk_not_copy() {
+    struct NotCopy(i32);
+
+    let mut rb = Rb::<Array<NotCopy, 4>>::default();
+    rb.push_iter((0..4).map(NotCopy));
+    rb.skip(3);
+    rb.push_iter((4..6).map(NotCopy));
+
+    let mut expected = [[3, 4], [5, 0]].into_iter();
+    rb.for_each_chunk(2, |chunk| assert!(chunk.iter().map(|x| x.0).eq(expected.next().unwrap().into_iter().take(chunk.len()))));
+    assert!(expected.next().is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn chunks() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..8);
+    rb.skip(6);
+    rb.push_iter(8..12);
+    assert_eq!(rb.as_slices(), (&[6, 7][..], &[8, 9, 10, 11][..]));
+
+    let mut chunks = rb.chunks(3);
+    assert_eq!(chunks.next(), Some(&[6, 7, 8][..]));
+    assert_eq!(chunks.next(), Some(&[9, 10, 11][..]));
+    assert_eq!(chunks.next(), None);
+    drop(chunks);
+    assert!(rb.is_empty());
+
+    rb.push_iter(12..18);
+    assert_eq!(rb.as_slices(), (&[12, 13, 14, 15][..], &[16, 17][..]));
+
+    let mut chunks = rb.chunks(4);
+    assert_eq!(chunks.next(), Some(&[12, 13, 14, 15][..]));
+    chunks.commit();
+    assert_eq!(chunks.next(), Some(&[16, 17][..]));
+    assert_eq!(chunks.next(), None);
+    drop(chunks);
+    assert!(rb.is_empty());
+
+    rb.push_iter(18..23);
+    let mut chunks = rb.chunks(2);
+    assert_eq!(chunks.next(), Some(&[18, 19][..]));
+    assert_eq!(chunks.next(), Some(&[20, 21][..]));
+    drop(chunks);
+    assert!(rb.iter().copied().eq([22]));
+}
+
+#[test]
+fn push_iter_leftover() {
+    use crate::wrap::{CachingProd, Prod};
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let mut iter = 0..10;
+    assert_eq!(rb.push_iter(&mut iter), 4);
+    assert_eq!(iter, 4..10);
+    rb.clear();
+
+    {
+        let mut prod = Prod::new(&rb);
+        assert_eq!(prod.push_iter(&mut iter), 4);
+        assert_eq!(iter, 8..10);
+    }
+    rb.clear();
+    {
+        let mut prod = CachingProd::new(&rb);
+        assert_eq!(prod.push_iter(&mut iter), 2);
+        assert_eq!(iter, 10..10);
+        assert_eq!(prod.push_iter(&mut (10..20)), 2);
+    }
+    {
+        let mut prod = Prod::new(&rb).freeze();
+        let mut iter = 20..30;
+        assert_eq!(prod.push_iter(&mut iter), 0);
+        assert_eq!(iter, 20..30);
+    }
+    assert!(rb.iter().copied().eq([8, 9, 10, 11]));
+}
+
+#[test]
+fn consume_peeked() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_iter(0..4);
+    cons.skip(2);
+    prod.push_iter(4..6);
+
+    let count = cons.iter().position(|x| *x == 4).unwrap();
+    assert_eq!(count, 2);
+    cons.consume_peeked(count);
+
+    assert!(cons.iter().copied().eq([4, 5]));
+    assert_eq!(cons.try_pop(), Some(4));
+    assert_eq!(cons.try_pop(), Some(5));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[test]
+fn is_sorted() {
+    let mut rb = Rb::<Array<f32, 4>>::default();
+    assert!(rb.is_sorted());
+
+    rb.push_slice(&[0.0, 0.0, 0.0]);
+    rb.skip(3);
+    rb.push_slice(&[1.0, 2.0, 2.0, 3.0]);
+    assert!(!rb.as_slices().1.is_empty());
+    assert!(rb.is_sorted());
+
+    rb.skip(1);
+    rb.try_push(0.0).unwrap();
+    assert!(!rb.is_sorted());
+
+    rb.clear();
+    rb.push_slice(&[1.0, f32::NAN]);
+    assert!(!rb.is_sorted());
+    rb.skip(1);
+    assert!(rb.is_sorted());
+}
+
+#[test]
+fn binary_search() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    assert_eq!(rb.binary_search(&0), Err(0));
+
+    rb.push_slice(&[0; 5]);
+    rb.skip(5);
+    rb.push_slice(&[10, 20, 30, 40, 50, 60]);
+    assert_eq!(rb.as_slices(), (&[10, 20, 30][..], &[40, 50, 60][..]));
+
+    // Inside slices.
+    assert_eq!(rb.binary_search(&20), Ok(1));
+    assert_eq!(rb.binary_search(&50), Ok(4));
+    assert_eq!(rb.binary_search(&15), Err(1));
+    assert_eq!(rb.binary_search(&55), Err(5));
+    // At the boundary between slices.
+    assert_eq!(rb.binary_search(&30), Ok(2));
+    assert_eq!(rb.binary_search(&40), Ok(3));
+    assert_eq!(rb.binary_search(&35), Err(3));
+    // Outside.
+    assert_eq!(rb.binary_search(&5), Err(0));
+    assert_eq!(rb.binary_search(&65), Err(6));
+
+    for x in 0..70 {
+        assert_eq!(rb.binary_search(&x).unwrap_or_else(|i| i), rb.iter().filter(|y| **y < x).count());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_exact_size_iter() {
+    let mut rb = Rb::<Array<u64, 8192>>::default();
+    rb.push_iter(0..100);
+    rb.skip(100);
+
+    let items = (0..10_000).collect::<alloc::vec::Vec<u64>>();
+    let mut iter = items.into_iter();
+    assert_eq!(rb.push_exact_size_iter(&mut iter), 8192);
+    assert_eq!(iter.len(), 10_000 - 8192);
+    assert!(iter.eq(8192..10_000));
+    assert!(rb.iter().copied().eq(0..8192));
+
+    rb.skip(8000);
+    assert_eq!(rb.push_exact_size_iter((0..10u32).map(u64::from)), 10);
+    assert_eq!(rb.push_exact_size_iter(core::iter::empty()), 0);
+    assert!(rb.iter().copied().eq((8000..8192).chain(0..10)));
+}
@@ -0,0 +1,104 @@
+use super::Rb;
+use crate::{rb::PowerOfTwoRb, storage::Array, traits::*, wrap::Counted};
+
+#[test]
+fn totals() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let mut pushed = 0;
+    for i in 0..10 {
+        pushed += rb.push_iter(0..(i % 4));
+        assert_eq!(rb.total_written(), pushed as u64);
+        rb.skip(i as usize % 3);
+        assert_eq!(rb.total_read(), pushed as u64 - rb.occupied_len() as u64);
+    }
+    assert!(rb.total_written() > 2 * rb.capacity().get() as u64);
+}
+
+#[test]
+fn wrappers() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    {
+        let (mut prod, mut cons) = rb.split_ref();
+        for i in 0..20 {
+            prod.try_push(i).unwrap();
+            assert_eq!(cons.try_pop(), Some(i));
+        }
+        assert_eq!(prod.total_written(), 20);
+        assert_eq!(cons.total_read(), 20);
+    }
+    {
+        let (prod, cons) = rb.split_ref();
+        let (mut prod, mut cons) = (prod.freeze(), cons.freeze());
+        prod.push_slice(&[0, 1, 2]);
+        assert_eq!(cons.total_written(), 20);
+        prod.commit();
+        cons.fetch();
+        assert_eq!(cons.skip(2), 2);
+        cons.commit();
+        assert_eq!(prod.total_written(), 23);
+        assert_eq!(prod.total_read(), 22);
+    }
+    // Counted wrapper doesn't interfere with ring buffer counters.
+    let mut rb = Counted::new(rb);
+    rb.push_iter_overwrite(0..8);
+    assert_eq!((rb.total_written(), rb.total_read()), (31, 27));
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (8, 5));
+}
+
+#[test]
+fn power_of_two() {
+    let mut rb = PowerOfTwoRb::new(Rb::<Array<i32, 4>>::default());
+    for i in 0..20 {
+        rb.try_push(i).unwrap();
+        assert_eq!(rb.try_pop(), Some(i));
+    }
+    assert_eq!((rb.total_written(), rb.total_read()), (20, 20));
+    assert_eq!(rb.indices(), (20 % 8, 20 % 8));
+}
+
+fn assert_totals_consistent<R: RingBuffer>(rb: &R) {
+    assert_eq!(rb.total_written() - rb.total_read(), rb.occupied_len() as u64);
+}
+
+#[test]
+fn removal_from_middle() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_iter(0..6);
+    rb.skip(3);
+    rb.push_iter(6..11);
+    assert_totals_consistent(&rb);
+
+    // Items after the range are shifted.
+    assert!(rb.drain(5..7).eq([8, 9]));
+    assert_totals_consistent(&rb);
+    // Items before the range are shifted.
+    assert!(rb.drain(1..2).eq([4]));
+    assert_totals_consistent(&rb);
+    assert!(rb.iter().copied().eq([3, 5, 6, 7, 10]));
+
+    rb.retain(|x| x % 2 == 1);
+    assert_totals_consistent(&rb);
+    assert_eq!(rb.pop_find(|x| *x == 5), Some(5));
+    assert_totals_consistent(&rb);
+
+    let mut cursor = rb.cursor_mut();
+    cursor.advance();
+    assert_eq!(cursor.remove_current(), Some(7));
+    assert_totals_consistent(&rb);
+
+    assert!(rb.take_all().eq([3]));
+    assert_totals_consistent(&rb);
+    assert_eq!((rb.total_written(), rb.total_read()), (11, 11));
+}
+
+#[test]
+fn removal_from_middle_power_of_two() {
+    let mut rb = PowerOfTwoRb::new(Rb::<Array<i32, 4>>::default());
+    rb.push_iter(0..4);
+    assert!(rb.drain(1..3).eq([1, 2]));
+    assert_totals_consistent(&rb);
+    rb.retain(|x| *x != 0);
+    assert!(rb.take_all().eq([3]));
+    assert_totals_consistent(&rb);
+    assert_eq!((rb.total_written(), rb.total_read()), (4, 4));
+}
@@ -0,0 +1,76 @@
+use super::Rb;
+use crate::{storage::MirroredHeap, traits::*};
+
+#[test]
+fn capacity_rounding() {
+    let rb = Rb::<MirroredHeap<u64>>::new_mirrored(1).unwrap();
+    let capacity = rb.capacity().get();
+    assert!(capacity >= 1);
+    assert_eq!((capacity * 8) % 4096, 0);
+
+    // Mapping size must be a multiple of item size too.
+    let rb = Rb::<MirroredHeap<[u8; 3]>>::new_mirrored(1000).unwrap();
+    let capacity = rb.capacity().get();
+    assert!(capacity >= 1000);
+    assert_eq!((capacity * 3) % 4096, 0);
+}
+
+#[test]
+fn never_wraps() {
+    let mut rb = Rb::<MirroredHeap<usize>>::new_mirrored(100).unwrap();
+    let capacity = rb.capacity().get();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut next_push = 0;
+    let mut next_pop = 0;
+    for step in [capacity / 3, capacity - 1, 7, capacity, 1].into_iter().cycle().take(20) {
+        assert!(prod.vacant_slices_mut().1.is_empty());
+        next_push += prod.push_iter(next_push..(next_push + step));
+
+        let (first, second) = cons.as_slices();
+        assert!(second.is_empty());
+        assert!(first.iter().copied().eq(next_pop..next_push));
+
+        let n = cons.skip(step / 2 + 1);
+        next_pop += n;
+    }
+    assert!(cons.as_slices().1.is_empty());
+    assert!(cons.pop_iter().eq(next_pop..next_push));
+}
+
+#[test]
+fn capacity_not_power_of_two() {
+    let mut rb = Rb::<MirroredHeap<u32>>::new_mirrored(3000).unwrap();
+    let capacity = rb.capacity().get();
+    assert!(!capacity.is_power_of_two());
+
+    let mut next_push = 0;
+    let mut next_pop = 0;
+    // Indices pass `2 * capacity` several times.
+    for step in [capacity - 1, capacity / 2 + 3, capacity].into_iter().cycle().take(12) {
+        next_push += rb.push_iter(next_push..(next_push + step as u32)) as u32;
+        assert_eq!(rb.as_slices().0.len(), rb.occupied_len());
+        assert_eq!(rb.vacant_slices().0.len(), rb.vacant_len());
+        assert_eq!(rb.occupied_len_contiguous(), rb.occupied_len());
+        assert_eq!(rb.vacant_len_contiguous(), rb.vacant_len());
+        assert!(rb.iter().copied().eq(next_pop..next_push));
+        next_pop += rb.skip(step) as u32;
+    }
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn drop_items() {
+    use alloc::rc::Rc;
+
+    let item = Rc::new(());
+    {
+        let mut rb = Rb::<MirroredHeap<Rc<()>>>::new_mirrored(1).unwrap();
+        let capacity = rb.capacity().get();
+        rb.push_iter((0..capacity).map(|_| item.clone()));
+        rb.skip(capacity / 2);
+        rb.push_iter((0..capacity).map(|_| item.clone()));
+        assert_eq!(Rc::strong_count(&item), capacity + 1);
+    }
+    assert_eq!(Rc::strong_count(&item), 1);
+}
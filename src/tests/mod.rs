@@ -4,22 +4,50 @@ use crate::LocalRb as Rb;
 use crate::SharedRb as Rb;
 
 mod access;
+mod aligned;
 mod basic;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod contiguous;
+mod counted;
+#[cfg(feature = "alloc")]
+mod cursor;
+#[cfg(feature = "alloc")]
+mod display;
+#[cfg(feature = "alloc")]
+mod drain;
 #[cfg(feature = "alloc")]
 mod drop;
 mod fmt_write;
 mod frozen;
+#[cfg(feature = "alloc")]
+mod fuzz;
 mod hold;
+mod in_place;
 mod init;
 mod iter;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(all(feature = "mmap", any(target_os = "linux", target_os = "android")))]
+mod mirrored;
 mod new;
 mod overwrite;
 #[cfg(feature = "std")]
+mod par;
+#[cfg(feature = "bytemuck")]
+mod pod;
+mod pow2;
+mod rate;
+#[cfg(feature = "std")]
 mod read_write;
+mod sampled;
+#[cfg(feature = "serde")]
+mod serde;
 #[cfg(feature = "std")]
 mod shared;
 #[cfg(feature = "alloc")]
 mod skip;
 mod slice;
+mod txn;
 mod unsized_;
 mod zero_sized;
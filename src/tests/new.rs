@@ -43,3 +43,146 @@ fn from_vec() {
     assert_eq!(cons.try_pop(), Some(5));
     assert_eq!(cons.try_pop(), None);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn new_aligned() {
+    use crate::storage::Heap;
+
+    let mut rb = Rb::<Heap<u16>>::new_aligned(5, 256);
+    assert_eq!(rb.capacity().get(), 5);
+    assert_eq!(rb.vacant_slices_mut().0.as_ptr() as usize % 256, 0);
+
+    rb.push_iter(0..7);
+    assert!(rb.iter().copied().eq(0..5));
+
+    let (_, cons) = rb.split();
+    assert!(cons.into_iter().eq(0..5));
+
+    let boxed = alloc::boxed::Box::<[_]>::from(Heap::<u16>::new_aligned(3, 64));
+    assert_eq!(boxed.len(), 3);
+}
+
+#[test]
+fn static_capacity() {
+    use crate::StaticRb;
+
+    const CAPACITY: usize = StaticRb::<i32, 8>::CAPACITY;
+    let buf = [0u8; StaticRb::<i32, 8>::CAPACITY];
+    assert_eq!(CAPACITY, 8);
+    assert_eq!(buf.len(), 8);
+    assert_eq!(StaticRb::<i32, 8>::default().capacity().get(), CAPACITY);
+    assert_eq!(Rb::<Array<i32, 3>>::CAPACITY, 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn prelude_aliases() {
+    use crate::prelude::{HeapCons, HeapProd, HeapRb, StaticCons, StaticProd, StaticRb};
+
+    struct Pipe {
+        prod: HeapProd<i32>,
+        cons: HeapCons<i32>,
+    }
+
+    let (prod, cons) = HeapRb::<i32>::new(2).split();
+    let mut pipe = Pipe { prod, cons };
+    pipe.prod.try_push(1).unwrap();
+    assert_eq!(pipe.cons.try_pop(), Some(1));
+
+    struct StaticPipe<'a> {
+        prod: StaticProd<'a, i32, 2>,
+        cons: StaticCons<'a, i32, 2>,
+    }
+
+    let mut rb = StaticRb::<i32, 2>::default();
+    let (prod, cons) = rb.split_ref();
+    let mut pipe = StaticPipe { prod, cons };
+    pipe.prod.try_push(2).unwrap();
+    assert_eq!(pipe.cons.try_pop(), Some(2));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_iter() {
+    use crate::storage::Heap;
+
+    let rb = (0..10).collect::<Rb<Heap<i32>>>();
+    assert_eq!(rb.capacity().get(), 10);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq(0..10));
+
+    let rb = (0..10).filter(|x| x % 2 == 0).collect::<Rb<Heap<i32>>>();
+    assert_eq!(rb.capacity().get(), 5);
+    assert!(rb.iter().copied().eq([0, 2, 4, 6, 8]));
+
+    let rb = core::iter::empty().collect::<Rb<Heap<i32>>>();
+    assert_eq!(rb.capacity().get(), 1);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn extend() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 1]);
+
+    let mut iter = 2..6;
+    rb.extend(&mut iter);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq(0..4));
+    assert!(iter.eq(4..6));
+
+    rb.extend([6]);
+    assert!(rb.iter().copied().eq(0..4));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn index_u32() {
+    let mut rb = crate::HeapRb32::<i32>::new(3);
+    for i in 0..100 {
+        assert_eq!(rb.push_slice(&[i, i + 1]), 2);
+        assert_eq!(rb.occupied_len(), 2);
+        assert_eq!(rb.vacant_len(), 1);
+        assert!(rb.pop_iter().eq([i, i + 1]));
+        let (read, write) = rb.indices();
+        assert_eq!(read, write);
+        assert!(write < 6);
+    }
+
+    let (mut prod, mut cons) = rb.split();
+    prod.push_iter(0..3);
+    assert!(cons.pop_iter().eq(0..3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn clone_local() {
+    use crate::{storage::Heap, LocalRb};
+    use alloc::rc::Rc;
+
+    let items = [0, 1, 2, 3].map(Rc::new);
+    let mut rb = LocalRb::<Array<Rc<i32>, 8>>::default();
+    rb.push_iter((0..6).map(Rc::new));
+    rb.skip(6);
+    rb.push_iter(items.iter().cloned());
+    assert!(!rb.as_slices().1.is_empty());
+
+    let copy = rb.clone();
+    assert_eq!(copy.capacity(), rb.capacity());
+    assert_eq!(copy.occupied_len(), 4);
+    assert!(items.iter().all(|x| Rc::strong_count(x) == 3));
+
+    rb.try_pop();
+    rb.try_push(Rc::new(4)).unwrap();
+    *rb.first_mut().unwrap() = Rc::new(5);
+    assert!(rb.iter().map(|x| **x).eq([5, 2, 3, 4]));
+    assert!(copy.iter().map(|x| **x).eq([0, 1, 2, 3]));
+
+    drop(rb);
+    let heap = LocalRb::<Heap<Rc<i32>>>::from(copy.iter().cloned().collect::<Vec<_>>());
+    let heap_copy = heap.clone();
+    assert!(heap_copy.iter().map(|x| **x).eq([0, 1, 2, 3]));
+    drop((copy, heap, heap_copy));
+    assert!(items.iter().all(|x| Rc::strong_count(x) == 1));
+}
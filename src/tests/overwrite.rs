@@ -1,5 +1,8 @@
 use super::Rb;
-use crate::{storage::Array, traits::*};
+use crate::{
+    storage::Array,
+    traits::{ring_buffer::OverwriteReport, *},
+};
 
 #[test]
 fn push() {
@@ -27,3 +30,53 @@ fn push_slice() {
     rb.push_slice_overwrite(&[0, 1, 2, 3, 4, 5]);
     assert!(rb.iter().copied().eq([4, 5]));
 }
+
+#[test]
+fn next_push_overwrites() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+
+    assert!(!rb.next_push_overwrites());
+    assert_eq!(rb.push_overwrite(0), None);
+    assert!(!rb.next_push_overwrites() && !rb.is_at_capacity());
+    assert_eq!(rb.push_overwrite(1), None);
+    assert!(rb.next_push_overwrites() && rb.is_at_capacity());
+    assert_eq!(rb.push_overwrite(2), Some(0));
+    assert!(rb.next_push_overwrites());
+}
+
+#[test]
+fn push_slice_reporting() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+
+    let report = rb.push_slice_overwrite_reporting(&[0, 1]);
+    assert_eq!(
+        report,
+        OverwriteReport {
+            written: 2,
+            overwritten: 0,
+            dropped_due_to_truncation: 0
+        }
+    );
+
+    let report = rb.push_slice_overwrite_reporting(&[2, 3, 4]);
+    assert_eq!(
+        report,
+        OverwriteReport {
+            written: 3,
+            overwritten: 1,
+            dropped_due_to_truncation: 0
+        }
+    );
+    assert!(rb.iter().copied().eq([1, 2, 3, 4]));
+
+    let report = rb.push_slice_overwrite_reporting(&[5, 6, 7, 8, 9, 10]);
+    assert_eq!(
+        report,
+        OverwriteReport {
+            written: 4,
+            overwritten: 4,
+            dropped_due_to_truncation: 2
+        }
+    );
+    assert!(rb.iter().copied().eq([7, 8, 9, 10]));
+}
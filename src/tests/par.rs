@@ -0,0 +1,30 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[test]
+fn sum() {
+    let mut rb = Rb::<Array<u64, 64>>::default();
+    rb.push_iter(0..40);
+    rb.skip(30);
+    rb.push_iter(40..90);
+    assert!(!rb.as_slices().1.is_empty());
+
+    let sum = AtomicU64::new(0);
+    rb.par_for_each(|x| {
+        sum.fetch_add(*x, Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), rb.iter().sum::<u64>());
+}
+
+#[test]
+fn contiguous() {
+    let mut rb = Rb::<Array<u64, 64>>::default();
+    rb.push_iter(0..10);
+
+    let sum = AtomicU64::new(0);
+    rb.par_for_each(|x| {
+        sum.fetch_add(*x, Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), 45);
+}
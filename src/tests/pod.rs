@@ -0,0 +1,27 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |h, &b| (h ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+#[test]
+fn occupied_as_bytes() {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+    let mut rb = Rb::<Array<u32, 4>>::default();
+    rb.push_iter(0..4);
+    rb.skip(2);
+    rb.push_slice(&[0x04030201, 0x08070605]);
+
+    let (left, right) = rb.occupied_as_bytes();
+    assert_eq!(left.len(), 8);
+    assert_eq!(right.len(), 8);
+
+    let expected = [2u32, 3, 0x04030201, 0x08070605];
+    assert_eq!(
+        fnv1a(fnv1a(FNV_OFFSET, left), right),
+        fnv1a(FNV_OFFSET, bytemuck::cast_slice(&expected))
+    );
+    assert_eq!(&right[..4], &0x04030201u32.to_ne_bytes());
+}
@@ -0,0 +1,45 @@
+use super::Rb;
+use crate::{rb::PowerOfTwoRb, storage::Array, traits::*};
+
+#[test]
+fn wrap() {
+    let mut rb = PowerOfTwoRb::new(Rb::<Array<i32, 4>>::default());
+    let mut model = Rb::<Array<i32, 4>>::default();
+
+    for i in 0..20 {
+        let n = i % 5;
+        assert_eq!(rb.push_iter(i * 10..i * 10 + n), model.push_iter(i * 10..i * 10 + n));
+        assert_eq!(rb.indices(), model.indices());
+        assert_eq!(rb.occupied_len(), model.occupied_len());
+        assert_eq!(rb.vacant_len(), model.vacant_len());
+        assert!(rb.iter().eq(model.iter()));
+
+        assert_eq!(rb.skip(i as usize % 3), model.skip(i as usize % 3));
+        assert_eq!(rb.try_pop(), model.try_pop());
+        assert_eq!(rb.indices(), model.indices());
+        assert_eq!(rb.occupied_len(), model.occupied_len());
+        assert_eq!(rb.vacant_len(), model.vacant_len());
+    }
+}
+
+#[test]
+fn split_ref() {
+    let mut rb = PowerOfTwoRb::new(Rb::<Array<i32, 2>>::default());
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for i in 0..10 {
+        assert_eq!(prod.push_slice(&[2 * i, 2 * i + 1, -1]), 2);
+        assert!(prod.is_full());
+        assert_eq!(cons.occupied_len(), 2);
+        assert_eq!(cons.try_pop(), Some(2 * i));
+        assert_eq!(cons.try_pop(), Some(2 * i + 1));
+        assert!(cons.is_empty());
+        assert_eq!(prod.vacant_len(), 2);
+    }
+}
+
+#[test]
+#[should_panic]
+fn not_power_of_two() {
+    PowerOfTwoRb::new(Rb::<Array<i32, 3>>::default());
+}
@@ -0,0 +1,38 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, wrap::RateMetered};
+
+#[test]
+fn batches() {
+    let mut rb = Rb::<Array<usize, 4>>::default();
+    let (prod, mut cons) = rb.split_ref();
+    let mut prod = RateMetered::new(prod);
+    assert_eq!(prod.pushed_since_tick(), 0);
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(prod.pushed_since_tick(), 3);
+    assert_eq!(prod.pushed_since_tick(), 0);
+
+    for batch in 1..=4 {
+        cons.clear();
+        assert_eq!(prod.push_iter(0..batch), batch);
+        assert_eq!(prod.pushed_since_tick(), batch);
+    }
+
+    // Consumption doesn't affect the rate.
+    cons.clear();
+    assert_eq!(prod.pushed_since_tick(), 0);
+    prod.push_iter(0..4);
+    cons.skip(2);
+    prod.push_iter(0..2);
+    assert_eq!(prod.pushed_since_tick(), 6);
+}
+
+#[test]
+fn items_pushed_since() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    rb.push_iter(0..2);
+    let write = rb.write_index();
+    rb.skip(2);
+    rb.push_iter(0..3);
+    assert_eq!(rb.items_pushed_since(write), 3);
+}
@@ -132,3 +132,121 @@ fn read_from() {
 
     assert!(cons.pop_iter().eq([2; 3]));
 }
+
+#[test]
+fn blocking_writer() {
+    use crate::HeapRb;
+    use std::{io::Write, thread, vec::Vec};
+
+    const LEN: usize = 4096;
+    let data = (0..LEN).map(|i| i as u8).collect::<Vec<_>>();
+
+    let (prod, mut cons) = HeapRb::<u8>::new(7).split();
+    let cjh = thread::spawn(move || {
+        let mut received = Vec::new();
+        while received.len() < LEN {
+            if cons.pop_iter().map(|x| received.push(x)).count() == 0 {
+                thread::yield_now();
+            }
+        }
+        received
+    });
+
+    let mut writer = prod.into_blocking_writer();
+    writer.write_all(&data).unwrap();
+    assert_eq!(cjh.join().unwrap(), data);
+
+    // Consumer is dropped.
+    assert_eq!(writer.write(&[0; 8]).unwrap(), 7);
+    assert_eq!(writer.write(&[0; 8]).unwrap(), 0);
+}
+
+#[test]
+fn reader_writer() {
+    use std::{io::Write, vec::Vec};
+
+    fn copy_all(reader: impl Read, writer: &mut dyn Write) -> io::Result<u64> {
+        let mut reader = reader;
+        io::copy(&mut reader, writer)
+    }
+
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut writer, mut reader) = (prod.into_writer(), cons.into_reader());
+
+    writer.write_all(b"hello").unwrap();
+    let mut out = Vec::new();
+    // Empty ring buffer results in `WouldBlock` error.
+    assert_eq!(copy_all(&mut reader, &mut out).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+    assert_eq!(out, b"hello");
+
+    assert_eq!(writer.write(&[0; 16]).unwrap(), 8);
+    assert_eq!(writer.write(&[0; 16]).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+    assert_eq!(reader.into_inner().occupied_len(), 8);
+}
+
+#[test]
+fn writer_flush() {
+    use std::io::Write;
+
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (prod, cons) = rb.split_ref();
+    let mut writer = prod.freeze().into_writer();
+
+    writer.write_all(b"hello").unwrap();
+    assert!(cons.is_empty());
+    // Flushing the writer commits items pushed to the frozen producer.
+    writer.flush().unwrap();
+    assert_eq!(cons.occupied_len(), 5);
+}
+
+#[test]
+fn drain_to_writer() {
+    use std::{io::Write, vec::Vec};
+
+    struct ChunkWriter {
+        data: Vec<u8>,
+        chunk: usize,
+        limit: usize,
+    }
+
+    impl Write for ChunkWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.chunk).min(self.limit - self.data.len());
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    rb.push_slice(&[0; 5]);
+    rb.skip(5);
+    rb.push_slice(&[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(rb.as_slices(), (&[1, 2, 3][..], &[4, 5, 6, 7][..]));
+
+    let mut writer = ChunkWriter {
+        data: Vec::new(),
+        chunk: 8,
+        limit: 16,
+    };
+    assert_eq!(rb.drain_to_writer(&mut writer).unwrap(), 7);
+    assert_eq!(writer.data, [1, 2, 3, 4, 5, 6, 7]);
+    assert!(rb.is_empty());
+
+    // Short write stops draining.
+    rb.push_slice(&[8, 9, 10, 11, 12]);
+    writer.chunk = 2;
+    assert_eq!(rb.drain_to_writer(&mut writer).unwrap(), 2);
+    assert_eq!(writer.data, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert!(rb.iter().copied().eq([10, 11, 12]));
+
+    writer.chunk = 8;
+    writer.limit = 10;
+    assert_eq!(rb.drain_to_writer(&mut writer).unwrap(), 1);
+    assert_eq!(writer.data, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert!(rb.iter().copied().eq([11, 12]));
+    assert_eq!(rb.drain_to_writer(&mut writer).unwrap(), 0);
+}
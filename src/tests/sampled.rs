@@ -0,0 +1,36 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, wrap::Sampled};
+
+#[test]
+fn histogram() {
+    let mut rb = Sampled::<_, 4>::new(Rb::<Array<i32, 8>>::default());
+
+    // Occupancy after each push: 1..=8.
+    for i in 0..8 {
+        rb.try_push(i).unwrap();
+    }
+    assert_eq!(rb.histogram(), [1, 2, 2, 3]);
+
+    // Occupancy after each pop: 7..=0.
+    for i in 0..8 {
+        assert_eq!(rb.try_pop(), Some(i));
+    }
+    assert_eq!(rb.histogram(), [3, 4, 4, 5]);
+
+    rb.reset_histogram();
+    assert_eq!(rb.histogram(), [0; 4]);
+}
+
+#[test]
+fn batch() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut cons) = (Sampled::<_, 2>::new(prod), Sampled::<_, 2>::new(cons));
+
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3, 4, 5]), 6);
+    assert_eq!(prod.histogram(), [0, 1]);
+
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(cons.try_pop(), Some(5));
+    assert_eq!(cons.histogram(), [2, 0]);
+}
@@ -0,0 +1,41 @@
+use crate::{traits::*, HeapRb, SharedRb};
+use alloc::{string::String, vec::Vec};
+
+#[test]
+fn round_trip_wrapped() {
+    let mut rb = HeapRb::<String>::new(4);
+    rb.push_iter(["a", "b", "c"].into_iter().map(String::from));
+    rb.skip(2);
+    rb.push_iter(["d", "e"].into_iter().map(String::from));
+    assert_eq!(rb.as_slices().0.len(), 2);
+    assert_eq!(rb.as_slices().1.len(), 1);
+
+    let json = serde_json::to_string(&rb).unwrap();
+    assert_eq!(json, r#"{"capacity":4,"items":["c","d","e"]}"#);
+    assert_eq!(rb.read_index(), 2);
+    assert_eq!(rb.write_index(), 5);
+
+    let mut other: HeapRb<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(other.capacity().get(), 4);
+    assert_eq!(other.pop_iter().collect::<Vec<_>>(), ["c", "d", "e"]);
+}
+
+#[test]
+fn shared() {
+    let rb = SharedRb::<crate::storage::Array<i32, 3>>::from([1, 2, 3]);
+    let json = serde_json::to_string(&rb).unwrap();
+    let other: SharedRb<crate::storage::Heap<i32>> = serde_json::from_str(&json).unwrap();
+    assert!(other.is_full());
+    assert!(other.iter().copied().eq(1..4));
+
+    let other: HeapRb<i32> = serde_json::from_str("[5, [1, 2]]").unwrap();
+    assert_eq!(other.capacity().get(), 5);
+    assert_eq!(other.occupied_len(), 2);
+}
+
+#[test]
+fn reject() {
+    assert!(serde_json::from_str::<HeapRb<i32>>(r#"{"capacity":2,"items":[1,2,3]}"#).is_err());
+    assert!(serde_json::from_str::<HeapRb<i32>>(r#"{"capacity":0,"items":[]}"#).is_err());
+    assert!(serde_json::from_str::<HeapRb<i32>>(r#"{"items":[]}"#).is_err());
+}
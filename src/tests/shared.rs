@@ -1,5 +1,10 @@
-use crate::{storage::Heap, traits::*, SharedRb};
-use std::{cell::Cell, thread, thread::sleep, time::Duration, vec::Vec};
+use crate::{
+    storage::Heap,
+    traits::*,
+    wrap::{Cons, Prod},
+    SharedRb,
+};
+use std::{cell::Cell, sync::Arc, thread, thread::sleep, time::Duration, vec::Vec};
 
 fn yield_() {
     sleep(Duration::from_millis(1));
@@ -68,3 +73,163 @@ fn non_sync() {
     pjh.join().unwrap();
     cjh.join().unwrap();
 }
+
+#[test]
+fn consistent_indices() {
+    const COUNT: usize = 10_000;
+    let rb = SharedRb::<Heap<usize>>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    let obs = prod.observe();
+
+    let pjh = thread::spawn(move || {
+        let mut iter = 0..COUNT;
+        while !iter.is_empty() {
+            prod.push_iter(&mut iter);
+            thread::yield_now();
+        }
+    });
+    let cjh = thread::spawn(move || {
+        let mut count = 0;
+        while count < COUNT {
+            count += cons.pop_iter().count();
+            thread::yield_now();
+        }
+    });
+
+    let capacity = obs.capacity().get();
+    while !pjh.is_finished() || !cjh.is_finished() {
+        let (read, write) = obs.indices();
+        assert!((write + 2 * capacity - read) % (2 * capacity) <= capacity);
+        thread::yield_now();
+    }
+
+    pjh.join().unwrap();
+    cjh.join().unwrap();
+}
+
+#[test]
+fn busy_wait() {
+    const COUNT: usize = 1_000;
+    const CHUNK: usize = 3;
+    let rb = Arc::new(SharedRb::<Heap<usize>>::new(4));
+    let mut prod = Prod::new(rb.clone());
+    let mut cons = Cons::new(rb);
+
+    let pjh = thread::spawn(move || {
+        for i in 0..COUNT {
+            prod.busy_wait_vacant(CHUNK);
+            assert_eq!(prod.push_iter(i * CHUNK..(i + 1) * CHUNK), CHUNK);
+        }
+    });
+    let cjh = thread::spawn(move || {
+        for i in 0..(COUNT * CHUNK) {
+            cons.busy_wait_occupied(1);
+            assert_eq!(cons.try_pop(), Some(i));
+        }
+    });
+
+    pjh.join().unwrap();
+    cjh.join().unwrap();
+}
+
+#[test]
+fn rejoin() {
+    let (mut prod, mut cons) = SharedRb::<Heap<i32>>::new(4).split();
+    prod.push_slice(&[0, 1, 2]);
+    assert_eq!(cons.try_pop(), Some(0));
+
+    let (other_prod, other_cons) = SharedRb::<Heap<i32>>::new(4).split();
+    let (prod, other_cons) = SharedRb::rejoin(prod, other_cons).unwrap_err();
+    drop((other_prod, other_cons));
+
+    let obs = prod.observe();
+    let (prod, cons) = SharedRb::rejoin(prod, cons).unwrap_err();
+    drop(obs);
+
+    let rb = SharedRb::rejoin(prod, cons).unwrap();
+    assert!(rb.iter().copied().eq([1, 2]));
+
+    let (mut prod, mut cons) = rb.split();
+    prod.try_push(3).unwrap();
+    assert!(cons.pop_iter().eq([1, 2, 3]));
+}
+
+#[test]
+fn split_with_shutdown() {
+    let (mut prod, mut cons, flag) = SharedRb::<Heap<i32>>::new(4).split_with_shutdown();
+    assert!(!prod.should_stop() && !cons.should_stop());
+
+    let cjh = thread::spawn(move || {
+        let mut items = Vec::new();
+        while !cons.should_stop() {
+            items.extend(cons.pop_iter());
+            yield_();
+        }
+        items.extend(cons.pop_iter());
+        items
+    });
+
+    prod.push_slice(&[0, 1, 2]);
+    prod.stop();
+    assert!(prod.should_stop());
+    assert_eq!(cjh.join().unwrap(), [0, 1, 2]);
+
+    assert!(flag.load(core::sync::atomic::Ordering::Acquire));
+}
+
+#[test]
+fn double_buffered() {
+    use crate::wrap::DoubleBuffered;
+
+    const COUNT: usize = 10_000;
+    let db = DoubleBuffered::new(SharedRb::<Heap<usize>>::new(4), SharedRb::<Heap<usize>>::new(7));
+    let (mut prod, mut cons) = db.split();
+
+    let pjh = thread::spawn(move || {
+        let mut swaps = 0;
+        for i in 0..COUNT {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+            if i % 3 == 0 && prod.swap() {
+                swaps += 1;
+            }
+        }
+        assert_eq!(prod.epoch(), swaps);
+        swaps
+    });
+
+    let mut next = 0;
+    while next < COUNT {
+        match cons.try_pop() {
+            Some(i) => {
+                assert_eq!(i, next);
+                next += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    let swaps = pjh.join().unwrap();
+    assert!(cons.try_pop().is_none());
+    assert_eq!(cons.epoch(), swaps);
+}
+
+#[test]
+fn double_buffered_observe() {
+    use crate::wrap::DoubleBuffered;
+
+    let db = DoubleBuffered::new(SharedRb::<Heap<i32>>::new(2), SharedRb::<Heap<i32>>::new(2));
+    let (mut prod, mut cons) = db.split();
+
+    assert!(prod.swap());
+    prod.try_push(0).unwrap();
+
+    // Observer methods don't switch.
+    assert_eq!(cons.occupied_len(), 1);
+    assert_eq!(cons.epoch(), 0);
+    assert!(!prod.swap());
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.epoch(), 1);
+    assert!(prod.swap());
+}
@@ -70,3 +70,31 @@ fn skip_drop() {
     // Check that items are dropped
     assert_eq!(Rc::strong_count(&rc), 1);
 }
+
+#[test]
+fn skip_prefix() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[0; 6]);
+    cons.skip(6);
+    prod.push_slice(b"MAGIC!");
+    assert!(!cons.as_slices().1.is_empty());
+
+    // Partial match
+    assert!(!cons.skip_prefix(b"MAGIX"));
+    assert_eq!(cons.occupied_len(), 6);
+
+    // Too short
+    assert!(!cons.skip_prefix(b"MAGIC!!"));
+    assert_eq!(cons.occupied_len(), 6);
+
+    // Match across the wrap
+    assert!(cons.skip_prefix(b"MAGIC"));
+    assert!(cons.iter().eq(b"!"));
+
+    assert!(cons.skip_prefix(b""));
+    assert!(cons.skip_prefix(b"!"));
+    assert!(cons.is_empty());
+    assert!(!cons.skip_prefix(b"!"));
+}
@@ -1,5 +1,6 @@
 use super::Rb;
-use crate::{storage::Array, traits::*, transfer};
+use crate::{broadcast, storage::Array, traits::*, transfer, transfer_all};
+use core::mem::MaybeUninit;
 
 #[test]
 fn push_pop_slice() {
@@ -95,3 +96,272 @@ fn move_slice_count() {
     assert_eq!(cons1.pop_slice(&mut tmp), 4);
     assert_eq!(tmp[0..4], [6, 7, 8, 9]);
 }
+
+#[test]
+fn transfer_wrapped() {
+    let mut rb0 = Rb::<Array<i32, 5>>::default();
+    let mut rb1 = Rb::<Array<i32, 7>>::default();
+    rb0.push_slice(&[0; 3]);
+    rb0.skip(3);
+    rb0.push_slice(&[1, 2, 3, 4, 5]);
+    rb1.push_slice(&[0; 5]);
+    rb1.skip(4);
+    assert_eq!(rb0.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+    assert_eq!(rb1.vacant_slices().0.len(), 2);
+
+    assert_eq!(transfer(&mut rb0, &mut rb1, Some(3)), 3);
+    assert!(rb0.iter().copied().eq([4, 5]));
+    assert!(rb1.iter().copied().eq([0, 1, 2, 3]));
+
+    rb1.push_slice(&[6, 7]);
+    assert_eq!(transfer_all(&mut rb0, &mut rb1), 1);
+    assert!(rb0.iter().copied().eq([5]));
+    assert!(rb1.iter().copied().eq([0, 1, 2, 3, 6, 7, 4]));
+
+    rb1.skip(5);
+    assert_eq!(transfer_all(&mut rb1, &mut rb0), 2);
+    assert!(rb0.iter().copied().eq([5, 7, 4]));
+    assert!(rb1.is_empty());
+}
+
+#[test]
+fn pop_at_most() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    rb.push_iter(0..6);
+    rb.skip(4);
+    rb.push_iter(6..12);
+    let mut out = [0; 4];
+
+    // Limited by `max`.
+    assert_eq!(rb.pop_at_most(3, &mut out), 3);
+    assert_eq!(out[..3], [4, 5, 6]);
+
+    // Limited by output length.
+    assert_eq!(rb.pop_at_most(10, &mut out), 4);
+    assert_eq!(out, [7, 8, 9, 10]);
+
+    // Limited by occupied length.
+    assert_eq!(rb.pop_at_most(3, &mut out), 1);
+    assert_eq!(out[0], 11);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn copy_while() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    rb.push_slice(b"0123xy");
+    rb.skip(4);
+    rb.push_slice(b"ab cd");
+    let mut out = [0; 4];
+
+    // Stopped by predicate.
+    assert_eq!(rb.copy_while(|c| c.is_ascii_alphanumeric(), &mut out), 4);
+    assert_eq!(out, *b"xyab");
+    assert_eq!(rb.copy_while(|c| c.is_ascii_alphanumeric(), &mut out), 0);
+    assert_eq!(rb.try_pop(), Some(b' '));
+
+    // Stopped by output length.
+    assert_eq!(rb.copy_while(|c| c.is_ascii_alphanumeric(), &mut out[..1]), 1);
+    assert_eq!(out[0], b'c');
+
+    // Stopped by empty ring buffer.
+    assert_eq!(rb.copy_while(|c| c.is_ascii_alphanumeric(), &mut out), 1);
+    assert_eq!(out[0], b'd');
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn drain_while() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    rb.push_slice(&[0; 6]);
+    rb.skip(6);
+    rb.push_slice(&[2, 4, 6, 8, 9, 10]);
+    assert_eq!(rb.as_slices(), (&[2, 4][..], &[6, 8, 9, 10][..]));
+
+    assert_eq!(rb.drain_while(|x| x % 2 == 0), 4);
+    assert!(rb.iter().copied().eq([9, 10]));
+    assert_eq!(rb.drain_while(|x| x % 2 == 0), 0);
+    assert_eq!(rb.try_pop(), Some(9));
+    assert_eq!(rb.drain_while(|x| x % 2 == 0), 1);
+    assert!(rb.is_empty());
+    assert_eq!(rb.drain_while(|_| true), 0);
+}
+
+#[test]
+fn find_pattern() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    rb.push_slice(b"xxxx");
+    rb.skip(4);
+    rb.push_slice(b"ab\r\ncd\r\n");
+    assert_eq!(rb.as_slices(), (&b"ab\r\n"[..], &b"cd\r\n"[..]));
+
+    // In one slice.
+    assert_eq!(rb.find_pattern(b"\r\n"), Some(2));
+    assert_eq!(rb.find_pattern(b"d\r\n"), Some(5));
+    // Straddling.
+    assert_eq!(rb.find_pattern(b"\ncd"), Some(3));
+    assert_eq!(rb.find_pattern(b"ab\r\ncd\r\n"), Some(0));
+    // Absent.
+    assert_eq!(rb.find_pattern(b"\r\n\r\n"), None);
+    assert_eq!(rb.find_pattern(b"ab\r\ncd\r\n\r"), None);
+
+    assert_eq!(rb.find_pattern(b""), Some(0));
+}
+
+#[test]
+fn position() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0, 0]);
+    rb.skip(3);
+    rb.push_slice(&[1, 2, 3]);
+    assert_eq!(rb.as_slices(), (&[1][..], &[2, 3][..]));
+
+    assert_eq!(rb.position(|x| *x == 1), Some(0));
+    assert_eq!(rb.position(|x| *x > 2), Some(2));
+    assert_eq!(rb.peek_at(2), Some(&3));
+    assert_eq!(rb.position(|x| *x == 0), None);
+
+    assert!(rb.contains(&3));
+    assert!(!rb.contains(&0));
+}
+
+#[test]
+fn pop_front_slice() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[-1, -1]);
+    cons.skip(2);
+    prod.push_slice(&[0, 1, 2, 3]);
+
+    assert_eq!(*cons.pop_front_slice(), [0, 1]);
+    assert_eq!(cons.occupied_len(), 4);
+
+    assert_eq!(cons.pop_front_slice().commit(1), 1);
+    assert!(cons.iter().copied().eq([1, 2, 3]));
+
+    let chunk = cons.pop_front_slice();
+    assert_eq!(chunk.as_slice(), [1]);
+    assert_eq!(chunk.commit(10), 1);
+
+    let chunk = cons.pop_front_slice();
+    assert_eq!(*chunk, [2, 3]);
+    assert_eq!(chunk.commit(2), 2);
+    assert!(cons.is_empty());
+
+    let chunk = cons.pop_front_slice();
+    assert!(chunk.is_empty());
+    assert_eq!(chunk.commit(1), 0);
+}
+
+#[test]
+fn broadcast_to_many() {
+    struct Cloned<'a>(&'a core::cell::Cell<usize>);
+    impl<'a> Clone for Cloned<'a> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Self(self.0)
+        }
+    }
+
+    let clones = core::cell::Cell::new(0);
+    let mut rbs: [Rb<Array<Cloned, 2>>; 3] = Default::default();
+    rbs[0].push_iter([Cloned(&clones), Cloned(&clones)].into_iter());
+    rbs[2].push_iter([Cloned(&clones)].into_iter());
+
+    let [rb0, rb1, rb2] = &mut rbs;
+    let item = Cloned(&clones);
+    assert_eq!(broadcast(&item, &mut [rb0, rb1, rb2]), 2);
+    assert_eq!(clones.get(), 2);
+
+    let [rb0, rb1, rb2] = &mut rbs;
+    assert_eq!(broadcast(&item, &mut [rb0, rb1, rb2]), 1);
+    assert_eq!(clones.get(), 3);
+
+    let [rb0, rb1, rb2] = &mut rbs;
+    assert_eq!(broadcast(&item, &mut [rb0, rb1, rb2]), 0);
+    assert_eq!(clones.get(), 3);
+
+    assert!(rbs.iter().all(|rb| rb.is_full()));
+}
+
+#[test]
+fn push_refs() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0, 0]);
+    rb.skip(3);
+
+    let src = [1, 2, 3, 4, 5, 6];
+    let mut iter = src.iter();
+    assert_eq!(rb.push_refs(&mut iter), 4);
+    assert!(iter.eq(&src[4..]));
+    assert_eq!(rb.as_slices(), (&[1][..], &[2, 3, 4][..]));
+
+    rb.skip(2);
+    assert_eq!(rb.push_refs(src.iter().rev()), 2);
+    assert!(rb.iter().copied().eq([3, 4, 6, 5]));
+}
+
+#[test]
+fn pop_array() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0, 0]);
+    rb.skip(3);
+    rb.push_slice(&[1, 2, 3]);
+    assert_eq!(rb.as_slices(), (&[1][..], &[2, 3][..]));
+
+    assert_eq!(rb.pop_array::<0>(), Some([]));
+    assert_eq!(rb.pop_array::<4>(), None);
+    assert_eq!(rb.occupied_len(), 3);
+    assert_eq!(rb.pop_array::<3>(), Some([1, 2, 3]));
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn peek_into_uninit_exact() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0, 0]);
+    rb.skip(3);
+    rb.push_slice(&[1, 2, 3]);
+
+    let mut out = [MaybeUninit::<i32>::uninit(); 4];
+    assert_eq!(rb.peek_into_uninit_exact(&mut out), Err(3));
+    assert_eq!(rb.peek_into_uninit_exact(&mut out[..0]), Ok(()));
+    assert_eq!(rb.peek_into_uninit_exact(&mut out[..3]), Ok(()));
+    assert!(out[..3].iter().map(|x| unsafe { x.assume_init() }).eq([1, 2, 3]));
+    assert_eq!(rb.occupied_len(), 3);
+}
+
+#[test]
+fn push_array() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0]);
+    rb.skip(2);
+    rb.push_slice(&[1]);
+
+    assert_eq!(rb.push_array([2, 3, 4, 5]), Err([2, 3, 4, 5]));
+    assert_eq!(rb.occupied_len(), 1);
+    assert_eq!(rb.push_array([]), Ok(()));
+    assert_eq!(rb.push_array([2, 3, 4]), Ok(()));
+    assert_eq!(rb.as_slices(), (&[1, 2][..], &[3, 4][..]));
+    assert_eq!(rb.push_array([5]), Err([5]));
+}
+
+#[test]
+fn frame() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 0]);
+    rb.skip(2);
+
+    assert!(rb.push_frame(&[1, 2, 3]));
+    assert_eq!(rb.as_slices(), (&[1, 2][..], &[3][..]));
+    assert!(!rb.push_frame(&[4, 5]));
+    assert!(rb.push_frame(&[4]));
+    assert!(rb.push_frame(&[]));
+
+    assert_eq!(rb.pop_frame::<5>(), None);
+    assert_eq!(rb.pop_frame::<3>(), Some([1, 2, 3]));
+    assert_eq!(rb.pop_frame::<0>(), Some([]));
+    assert_eq!(rb.pop_frame::<1>(), Some([4]));
+    assert!(rb.is_empty());
+}
@@ -0,0 +1,53 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+
+#[test]
+fn commit() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..3);
+    rb.skip(3);
+    rb.push_iter(3..7);
+
+    let mut txn = rb.read_transaction();
+    assert!(txn.iter().copied().eq(3..7));
+    assert_eq!(txn.consume(5), 4);
+    assert_eq!(txn.as_slices(), (&[][..], &[][..]));
+    assert_eq!(txn.commit(), 4);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn rollback_on_drop() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..4);
+
+    {
+        let mut txn = rb.read_transaction();
+        txn.consume(2);
+        assert!(txn.iter().copied().eq(2..4));
+    }
+    assert!(rb.iter().copied().eq(0..4));
+
+    let mut txn = rb.read_transaction();
+    txn.consume(4);
+    txn.rollback();
+    assert!(rb.iter().copied().eq(0..4));
+}
+
+#[test]
+fn partial_commit() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_iter(0..2);
+    rb.skip(2);
+    rb.push_iter(2..6);
+    assert_eq!(rb.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+    let mut txn = rb.read_transaction();
+    assert_eq!(txn.consume(1), 1);
+    assert_eq!(txn.as_slices(), (&[3][..], &[4, 5][..]));
+    assert_eq!(txn.consume(2), 2);
+    assert_eq!(txn.as_slices(), (&[5][..], &[][..]));
+    assert_eq!(txn.consumed(), 3);
+    assert_eq!(txn.commit(), 3);
+    assert!(rb.iter().copied().eq([5]));
+}
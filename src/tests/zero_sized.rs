@@ -1,5 +1,6 @@
 use crate::{
     producer::Producer,
+    storage::{Heap, MAX_CAPACITY},
     traits::{Consumer, Observer, Split},
     HeapRb,
 };
@@ -47,3 +48,48 @@ fn basic() {
 
     assert!(cons.try_pop().is_none());
 }
+
+#[test]
+fn beyond_capacity() {
+    #[derive(Debug, PartialEq)]
+    struct Empty;
+
+    let mut rb = HeapRb::<Empty>::new(3);
+    for _ in 0..10 {
+        assert_eq!(rb.push_iter((0..5).map(|_| Empty)), 3);
+        assert!(rb.is_full());
+        assert_eq!(rb.occupied_len(), 3);
+        assert_eq!(rb.try_push(Empty), Err(Empty));
+
+        assert_eq!(rb.try_pop(), Some(Empty));
+        assert_eq!(rb.occupied_len(), 2);
+        assert_eq!(rb.pop_iter().count(), 2);
+        assert!(rb.is_empty());
+        assert_eq!(rb.try_pop(), None);
+    }
+}
+
+#[test]
+fn max_capacity() {
+    let mut rb = unsafe { HeapRb::<()>::from_raw_parts(Heap::new(MAX_CAPACITY), 2 * MAX_CAPACITY - 1, MAX_CAPACITY - 1) };
+    assert_eq!(rb.capacity().get(), MAX_CAPACITY);
+    assert!(rb.is_full());
+    assert_eq!(rb.occupied_len(), MAX_CAPACITY);
+    assert_eq!(rb.vacant_len(), 0);
+    assert!(rb.try_push(()).is_err());
+
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.occupied_len(), MAX_CAPACITY - 1);
+    assert_eq!(rb.vacant_len(), 1);
+    assert_eq!(rb.try_push(()), Ok(()));
+    assert!(rb.is_full());
+
+    rb.clear();
+    assert!(rb.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn capacity_too_large() {
+    let _ = HeapRb::<()>::new(MAX_CAPACITY + 1);
+}
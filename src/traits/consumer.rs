@@ -2,8 +2,20 @@ use super::{
     observer::{DelegateObserver, Observer},
     utils::modulus,
 };
-use crate::utils::{move_uninit_slice, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref};
-use core::{iter::Chain, mem::MaybeUninit, ptr, slice};
+use crate::utils::{
+    array_assume_init, move_uninit_slice, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref, uninit_array,
+};
+#[cfg(feature = "std")]
+use crate::wrap::RbReader;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    iter::{Chain, Enumerate},
+    mem::MaybeUninit,
+    ops::{Deref, Index},
+    ptr, slice,
+};
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
@@ -29,6 +41,13 @@ pub trait Consumer: Observer {
         self.set_read_index((self.read_index() + count) % modulus(self));
     }
 
+    /// Makes removal of items visible to the producer and fetches its changes.
+    ///
+    /// Does nothing by default. Wrappers that cache ring buffer indices (e.g. [`CachingCons`](`crate::CachingCons`)) override it.
+    /// Allows generic code to synchronize regardless of consumer type.
+    #[inline]
+    fn flush(&mut self) {}
+
     /// Provides a direct access to the ring buffer occupied memory.
     /// The difference from [`Self::as_slices`] is that this method provides slices of [`MaybeUninit`], so items may be moved out of slices.  
     ///
@@ -57,6 +76,20 @@ pub trait Consumer: Observer {
         self.unsafe_slices_mut(self.read_index(), self.write_index())
     }
 
+    /// Length of the first of [`Self::occupied_slices`].
+    ///
+    /// Computed from indices, mirrored storage has no second slice so the whole occupied length is returned for it.
+    #[inline]
+    fn occupied_len_contiguous(&self) -> usize {
+        let len = self.occupied_len();
+        if self.is_mirrored() {
+            len
+        } else {
+            let capacity = self.capacity().get();
+            usize::min(len, capacity - self.read_index() % capacity)
+        }
+    }
+
     /// Returns a pair of slices which contain, in order, the contents of the ring buffer.
     #[inline]
     fn as_slices(&self) -> (&[Self::Item], &[Self::Item]) {
@@ -75,6 +108,16 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns all items in the ring buffer as a single slice if they are stored contiguously, otherwise `None`.
+    ///
+    /// Empty ring buffer yields an empty slice.
+    fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+        match self.as_slices() {
+            (first, []) => Some(first),
+            _ => None,
+        }
+    }
+
     /// Returns a reference to the eldest item in the ring buffer, if exists.
     #[inline]
     fn first(&self) -> Option<&Self::Item> {
@@ -108,6 +151,34 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns a reference to the item at `index` counting from the eldest one, if exists.
+    fn peek_at(&self, index: usize) -> Option<&Self::Item> {
+        let (first, second) = self.as_slices();
+        match index.checked_sub(first.len()) {
+            None => first.get(index),
+            Some(index) => second.get(index),
+        }
+    }
+    /// Returns a mutable reference to the item at `index` counting from the eldest one, if exists.
+    fn peek_at_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+        let (first, second) = self.as_mut_slices();
+        match index.checked_sub(first.len()) {
+            None => first.get_mut(index),
+            Some(index) => second.get_mut(index),
+        }
+    }
+    /// Returns references to the two eldest items, if exist.
+    ///
+    /// Useful for parsers that need one item of lookahead.
+    fn peek_two(&self) -> (Option<&Self::Item>, Option<&Self::Item>) {
+        let (first, second) = self.as_slices();
+        match first {
+            [] => (None, None),
+            [a] => (Some(a), second.first()),
+            [a, b, ..] => (Some(a), Some(b)),
+        }
+    }
+
     /// Removes the eldest item from the ring buffer and returns it.
     ///
     /// Returns `None` if the ring buffer is empty.
@@ -165,6 +236,22 @@ pub trait Consumer: Observer {
         self.peek_slice_uninit(unsafe { slice_as_uninit_mut(elems) })
     }
 
+    /// Copies exactly `elems.len()` items from the ring buffer to an uninit slice without removing them from the ring buffer.
+    ///
+    /// If there are not enough items then nothing is written and `Err` containing the number of items in the ring buffer is returned.
+    fn peek_into_uninit_exact(&self, elems: &mut [MaybeUninit<Self::Item>]) -> Result<(), usize>
+    where
+        Self::Item: Copy,
+    {
+        let len = self.occupied_len();
+        if len < elems.len() {
+            return Err(len);
+        }
+        let count = self.peek_slice_uninit(elems);
+        debug_assert_eq!(count, elems.len());
+        Ok(())
+    }
+
     /// Removes items from the ring buffer and writes them into an uninit slice.
     ///
     /// Returns count of items been removed.
@@ -174,6 +261,28 @@ pub trait Consumer: Observer {
         count
     }
 
+    /// Removes exactly `elems.len()` items from the ring buffer and writes them into an uninit slice.
+    ///
+    /// If there are not enough items then nothing is removed or written and `Err` containing the number of items in the ring buffer is returned.
+    fn pop_uninit_exact(&mut self, elems: &mut [MaybeUninit<Self::Item>]) -> Result<(), usize> {
+        let len = self.occupied_len();
+        if len < elems.len() {
+            return Err(len);
+        }
+        let count = self.pop_slice_uninit(elems);
+        debug_assert_eq!(count, elems.len());
+        Ok(())
+    }
+
+    /// Removes exactly `N` items from the ring buffer and returns them as an array.
+    ///
+    /// If there are less than `N` items then nothing is removed and `None` is returned.
+    fn pop_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        let mut buf = uninit_array::<Self::Item, N>();
+        self.pop_uninit_exact(&mut buf).ok()?;
+        Some(unsafe { array_assume_init(buf) })
+    }
+
     /// Removes items from the ring buffer and writes them into a slice.
     ///
     /// Returns count of items been removed.
@@ -184,8 +293,108 @@ pub trait Consumer: Observer {
         self.pop_slice_uninit(unsafe { slice_as_uninit_mut(elems) })
     }
 
+    #[cfg(feature = "bytemuck")]
+    /// Returns a pair of byte slices which contain, in order, the contents of the ring buffer.
+    ///
+    /// Useful for hashing or checksumming contents without copying them.
+    fn occupied_as_bytes(&self) -> (&[u8], &[u8])
+    where
+        Self::Item: bytemuck::Pod,
+    {
+        let (left, right) = self.as_slices();
+        (bytemuck::cast_slice(left), bytemuck::cast_slice(right))
+    }
+
+    /// Removes at most `max` items from the ring buffer and writes them into a slice.
+    ///
+    /// Acts as [`Self::pop_slice`] with `elems` truncated to `max` items.
+    /// Useful for limiting number of bytes processed at once.
+    ///
+    /// Returns count of items been removed from the ring buffer.
+    fn pop_at_most(&mut self, max: usize, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let len = usize::min(max, elems.len());
+        self.pop_slice(&mut elems[..len])
+    }
+
+    /// Returns position (counting from the eldest item) of the first occurrence of `pattern` in the ring buffer.
+    ///
+    /// Pattern may be located across the end of the storage.
+    /// Empty pattern is found at position `0`.
+    fn find_pattern(&self, pattern: &[Self::Item]) -> Option<usize>
+    where
+        Self::Item: PartialEq,
+    {
+        let (left, right) = self.as_slices();
+        let len = left.len() + right.len();
+        if pattern.len() > len {
+            return None;
+        }
+        (0..=(len - pattern.len())).find(|&start| left.iter().chain(right).skip(start).zip(pattern).all(|(a, b)| a == b))
+    }
+
+    /// Returns position (counting from the eldest item) of the first item for which `pred` returns `true`.
+    ///
+    /// Returned position is consistent with [`Self::peek_at`].
+    fn position<F: FnMut(&Self::Item) -> bool>(&self, pred: F) -> Option<usize> {
+        let (left, right) = self.as_slices();
+        left.iter().chain(right).position(pred)
+    }
+
+    /// Checks whether the ring buffer contains an item equal to `x`.
+    fn contains(&self, x: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        let (left, right) = self.as_slices();
+        left.contains(x) || right.contains(x)
+    }
+
+    /// Removes leading items while `pred` returns `true` and writes them into a slice.
+    ///
+    /// Stops at the first item for which `pred` returns `false` (that item remains in the ring buffer),
+    /// when the ring buffer becomes empty or when `elems` is filled.
+    ///
+    /// Returns count of items been removed from the ring buffer.
+    fn copy_while<P: FnMut(&Self::Item) -> bool>(&mut self, mut pred: P, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let mut count = 0;
+        for (src, dst) in self.iter().zip(elems.iter_mut()) {
+            if !pred(src) {
+                break;
+            }
+            *dst = *src;
+            count += 1;
+        }
+        unsafe { self.advance_read_index(count) };
+        count
+    }
+
+    /// Removes items from the ring buffer and writes them into a slice using volatile reads.
+    ///
+    /// Each item is read with [`core::ptr::read_volatile`], so reads are not elided or merged by compiler.
+    /// Useful when storage is written by hardware (e.g. DMA engine).
+    ///
+    /// Returns count of items been removed from the ring buffer.
+    fn pop_slice_volatile(&mut self, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.occupied_slices();
+        let count = usize::min(elems.len(), left.len() + right.len());
+        for (src, dst) in left.iter().chain(right.iter()).zip(&mut elems[..count]) {
+            *dst = unsafe { ptr::read_volatile(src.as_ptr()) };
+        }
+        unsafe { self.advance_read_index(count) };
+        count
+    }
+
     /// Returns an iterator that removes items one by one from the ring buffer.
-    fn pop_iter(&mut self) -> PopIter<Self> {
+    fn pop_iter(&mut self) -> PopIter<'_, Self> {
         PopIter::new(self)
     }
 
@@ -197,6 +406,143 @@ pub trait Consumer: Observer {
         left.iter().chain(right.iter())
     }
 
+    /// Returns a read-only view of items in the ring buffer.
+    ///
+    /// Occupied slices are computed once on creation, so repeated access to the view doesn't read indices again.
+    /// Items pushed after the view creation are not visible through it.
+    fn borrow(&self) -> BorrowedView<'_, Self> {
+        let (left, right) = self.as_slices();
+        BorrowedView { left, right }
+    }
+
+    /// Returns the first contiguous slice of occupied items that can be consumed without copying.
+    ///
+    /// Items are removed only on [`PoppedSlice::commit`], nothing is removed if the returned value is just dropped.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..4);
+    ///
+    /// let chunk = rb.pop_front_slice();
+    /// assert_eq!(*chunk, [0, 1, 2, 3]);
+    /// assert_eq!(chunk.commit(3), 3);
+    /// assert!(rb.iter().copied().eq([3]));
+    /// # }
+    /// ```
+    fn pop_front_slice(&mut self) -> PoppedSlice<'_, Self> {
+        let len = self.as_slices().0.len();
+        PoppedSlice { cons: self, len }
+    }
+
+    /// Starts a read transaction.
+    ///
+    /// Items can be inspected and marked as consumed, but they are removed only on [`ReadTxn::commit`].
+    /// If transaction is dropped without commit then nothing is removed.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..4);
+    ///
+    /// let mut txn = rb.read_transaction();
+    /// assert_eq!(txn.consume(3), 3);
+    /// assert!(txn.iter().copied().eq([3]));
+    /// drop(txn);
+    /// assert_eq!(rb.occupied_len(), 4);
+    ///
+    /// let mut txn = rb.read_transaction();
+    /// txn.consume(3);
+    /// assert_eq!(txn.commit(), 3);
+    /// assert!(rb.iter().copied().eq([3]));
+    /// # }
+    /// ```
+    fn read_transaction(&mut self) -> ReadTxn<'_, Self> {
+        ReadTxn { cons: self, consumed: 0 }
+    }
+
+    /// Checks if items in the ring buffer are sorted in non-descending order from the eldest to the most recent one.
+    ///
+    /// Empty ring buffer is considered sorted.
+    fn is_sorted(&self) -> bool
+    where
+        Self::Item: PartialOrd,
+    {
+        let mut iter = self.iter();
+        match iter.next() {
+            Some(first) => iter
+                .try_fold(first, |prev, next| if prev <= next { Some(next) } else { None })
+                .is_some(),
+            None => true,
+        }
+    }
+
+    /// Binary searches items sorted in non-descending order from the eldest to the most recent one for `x`.
+    ///
+    /// Both occupied slices are treated as a single sorted sequence.
+    /// Returns position (counting from the eldest item) of the matching item in `Ok`
+    /// or position where `x` could be inserted while maintaining sorted order in `Err`.
+    /// If there are multiple matches then any one of them may be returned. Result is unspecified if items are not sorted.
+    fn binary_search(&self, x: &Self::Item) -> Result<usize, usize>
+    where
+        Self::Item: Ord,
+    {
+        let (left, right) = self.as_slices();
+        match left.last() {
+            Some(last) if x <= last => left.binary_search(x),
+            _ => right.binary_search(x).map(|i| i + left.len()).map_err(|i| i + left.len()),
+        }
+    }
+
+    /// Returns a front-to-back iterator containing references to items in the ring buffer along with their positions.
+    ///
+    /// Position is counted from the eldest item, so it can be passed to methods accepting logical positions.
+    ///
+    /// This iterator does not remove items out of the ring buffer.
+    fn iter_indexed(&self) -> IterIndexed<'_, Self> {
+        self.iter().enumerate()
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Returns a lending iterator over successive chunks of `n` items removing them from the ring buffer.
+    ///
+    /// The last chunk may contain less than `n` items.
+    /// Chunks are taken directly from the ring buffer memory. Only the chunk crossing the end of the storage is copied into an internal heap-allocated buffer.
+    ///
+    /// Items are removed only when [`Chunks::commit`] is called or the iterator is dropped.
+    ///
+    /// *Panics if `n` is zero.*
+    #[track_caller]
+    fn chunks(&mut self, n: usize) -> Chunks<'_, Self>
+    where
+        Self::Item: Copy,
+    {
+        assert!(n > 0, "Chunk size must be greater than zero");
+        Chunks {
+            cons: self,
+            size: n,
+            taken: 0,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Returns array containing clones of first `N` items in the ring buffer.
+    ///
+    /// If there are less than `N` items then remaining elements of the array are `None`.
+    ///
+    /// This method does not remove items out of the ring buffer.
+    fn snapshot_options<const N: usize>(&self) -> [Option<Self::Item>; N]
+    where
+        Self::Item: Clone,
+    {
+        let mut iter = self.iter();
+        core::array::from_fn(|_| iter.next().cloned())
+    }
+
     /// Returns a front-to-back iterator that returns mutable references to items in the ring buffer.
     ///
     /// This iterator does not remove items out of the ring buffer.
@@ -205,6 +551,26 @@ pub trait Consumer: Observer {
         left.iter_mut().chain(right.iter_mut())
     }
 
+    #[cfg(feature = "std")]
+    /// Calls `f` for each item in the ring buffer processing both [`Self::as_slices`] concurrently.
+    ///
+    /// The second slice is processed in a separate scoped thread, so order of calls is not specified.
+    /// If the second slice is empty then no thread is spawned.
+    fn par_for_each<F: Fn(&Self::Item) + Sync>(&self, f: F)
+    where
+        Self::Item: Sync,
+    {
+        let (left, right) = self.as_slices();
+        if right.is_empty() {
+            left.iter().for_each(f);
+        } else {
+            std::thread::scope(|s| {
+                s.spawn(|| right.iter().for_each(&f));
+                left.iter().for_each(&f);
+            });
+        }
+    }
+
     /// Removes at most `count` and at least `min(count, Self::len())` items from the buffer and safely drops them.
     ///
     /// If there is no concurring producer activity then exactly `min(count, Self::len())` items are removed.
@@ -227,30 +593,140 @@ pub trait Consumer: Observer {
     fn skip(&mut self, count: usize) -> usize {
         unsafe {
             let (left, right) = self.occupied_slices_mut();
-            for elem in left.iter_mut().chain(right.iter_mut()).take(count) {
-                ptr::drop_in_place(elem.as_mut_ptr());
-            }
             let actual_count = usize::min(count, left.len() + right.len());
+            let left_count = usize::min(actual_count, left.len());
+            ptr::drop_in_place(slice_assume_init_mut(&mut left[..left_count]));
+            ptr::drop_in_place(slice_assume_init_mut(&mut right[..actual_count - left_count]));
             self.advance_read_index(actual_count);
             actual_count
         }
     }
 
+    /// Removes first `count` items previously observed via [`Self::iter`] and safely drops them.
+    ///
+    /// Allows to peek items, decide how many of them to take and then commit only this prefix.
+    /// `count` must not be greater than the number of items (checked in debug builds), otherwise all items are removed.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..6);
+    ///
+    /// let count = rb.iter().take_while(|x| **x < 3).count();
+    /// rb.consume_peeked(count);
+    /// assert!(rb.iter().copied().eq(3..6));
+    /// # }
+    /// ```
+    fn consume_peeked(&mut self, count: usize) {
+        debug_assert!(count <= self.occupied_len(), "Cannot consume more items than peeked");
+        self.skip(count);
+    }
+
+    /// Removes leading items if they are equal to `prefix`.
+    ///
+    /// Returns `true` if the prefix matched and was removed, otherwise the ring buffer is left untouched and `false` is returned.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<u8, 8>>::default();
+    /// rb.push_slice(b"\xEF\xBB\xBFabc");
+    ///
+    /// assert!(rb.skip_prefix(b"\xEF\xBB\xBF"));
+    /// assert!(!rb.skip_prefix(b"\xEF\xBB\xBF"));
+    /// assert!(rb.iter().eq(b"abc"));
+    /// # }
+    /// ```
+    fn skip_prefix(&mut self, prefix: &[Self::Item]) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        let (left, right) = self.as_slices();
+        if left.len() + right.len() < prefix.len() {
+            return false;
+        }
+        let (prefix_left, prefix_right) = prefix.split_at(usize::min(left.len(), prefix.len()));
+        if left[..prefix_left.len()] != *prefix_left || right[..prefix_right.len()] != *prefix_right {
+            return false;
+        }
+        self.skip(prefix.len());
+        true
+    }
+
+    /// Removes and drops leading items while `pred` returns `true`.
+    ///
+    /// Stops at the first item for which `pred` returns `false` (that item remains in the ring buffer) or when the ring buffer becomes empty.
+    ///
+    /// Returns count of items been removed.
+    fn drain_while<F: FnMut(&Self::Item) -> bool>(&mut self, mut pred: F) -> usize {
+        let count = self.iter().take_while(|item| pred(item)).count();
+        self.skip(count)
+    }
+
+    /// Removes and drops the eldest items while `is_expired` returns `true`.
+    ///
+    /// Same as [`Self::drain_while`], intended for evicting outdated items (e.g. ones with timestamp older than TTL).
+    ///
+    /// Returns count of items been dropped.
+    fn expire_while<F: FnMut(&Self::Item) -> bool>(&mut self, is_expired: F) -> usize {
+        self.drain_while(is_expired)
+    }
+
+    /// Removes all items from the ring buffer passing each of them to `f` in order from the eldest one.
+    ///
+    /// If `f` panics then the item passed to it and all previous ones are removed and remaining items stay in the ring buffer.
+    ///
+    /// Returns count of items been removed.
+    fn pop_each<F: FnMut(Self::Item)>(&mut self, mut f: F) -> usize {
+        /// Commits removal of processed items even if `f` panics.
+        struct Guard<'a, C: Consumer + ?Sized> {
+            cons: &'a C,
+            count: usize,
+        }
+        impl<'a, C: Consumer + ?Sized> Drop for Guard<'a, C> {
+            fn drop(&mut self) {
+                unsafe { self.cons.advance_read_index(self.count) };
+            }
+        }
+
+        let (left, right) = self.occupied_slices();
+        let mut guard = Guard { cons: self, count: 0 };
+        for item in left.iter().chain(right) {
+            let item = unsafe { item.assume_init_read() };
+            guard.count += 1;
+            f(item);
+        }
+        guard.count
+    }
+
     /// Removes all items from the buffer and safely drops them.
     ///
     /// Returns the number of deleted items.
     fn clear(&mut self) -> usize {
         unsafe {
             let (left, right) = self.occupied_slices_mut();
-            for elem in left.iter_mut().chain(right.iter_mut()) {
-                ptr::drop_in_place(elem.as_mut_ptr());
-            }
             let count = left.len() + right.len();
+            ptr::drop_in_place(slice_assume_init_mut(left));
+            ptr::drop_in_place(slice_assume_init_mut(right));
             self.advance_read_index(count);
             count
         }
     }
 
+    #[cfg(feature = "std")]
+    /// Wraps the consumer into [`io::Read`] implementation.
+    ///
+    /// Unlike the consumer itself the wrapper is a concrete reader type suitable for generic bounds and trait objects.
+    fn into_reader(self) -> RbReader<Self>
+    where
+        Self: Consumer<Item = u8> + Sized,
+    {
+        RbReader::new(self)
+    }
+
     #[cfg(feature = "std")]
     /// Removes at most first `count` bytes from the ring buffer and writes them into a [`Write`] instance.
     /// If `count` is `None` then as much as possible bytes will be written.
@@ -260,7 +736,8 @@ pub trait Consumer: Observer {
     /// + `None`: ring buffer is full or `count` is `0`. In this case `write` isn't called at all.
     /// + `Some(Ok(n))`: `write` succeeded. `n` is number of bytes been written. `n == 0` means that `write` also returned `0`.
     /// + `Some(Err(e))`: `write` is failed and `e` is original error. In this case it is guaranteed that no items was written to the writer.
-    ///    To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
+    ///   To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
+    #[track_caller]
     fn write_into<S: Write>(&mut self, writer: &mut S, count: Option<usize>) -> Option<io::Result<usize>>
     where
         Self: Consumer<Item = u8>,
@@ -280,6 +757,40 @@ pub trait Consumer: Observer {
         unsafe { self.advance_read_index(write_count) };
         Some(Ok(write_count))
     }
+
+    #[cfg(feature = "std")]
+    /// Removes bytes from the ring buffer and writes them into a [`Write`] instance until all bytes that were present at the moment of call are written.
+    ///
+    /// Writes slice by slice, interrupted writes are retried.
+    /// Stops earlier on a short write, i.e. when `write` accepts fewer bytes than passed to it.
+    ///
+    /// Returns total number of bytes written.
+    /// If `write` failed then the error is returned only if no bytes were written before, otherwise it is discarded.
+    fn drain_to_writer<W: Write>(&mut self, writer: &mut W) -> io::Result<usize>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let mut remaining = self.occupied_len();
+        let mut total = 0;
+        while remaining > 0 {
+            let (left, _) = self.as_slices();
+            let count = usize::min(remaining, left.len());
+            let write_count = match writer.write(&left[..count]) {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) if total == 0 => return Err(e),
+                Err(_) => break,
+            };
+            assert!(write_count <= count);
+            unsafe { self.advance_read_index(write_count) };
+            total += write_count;
+            remaining -= write_count;
+            if write_count < count {
+                break;
+            }
+        }
+        Ok(total)
+    }
 }
 
 /// Owning ring buffer iterator.
@@ -366,12 +877,225 @@ impl<'a, C: Consumer> Iterator for PopIter<'a, C> {
 
 impl<'a, C: Consumer> ExactSizeIterator for PopIter<'a, C> {}
 
+/// Read-only view of ring buffer contents.
+///
+/// Created by [`Consumer::borrow`].
+pub struct BorrowedView<'a, C: Consumer + ?Sized> {
+    left: &'a [C::Item],
+    right: &'a [C::Item],
+}
+
+impl<'a, C: Consumer + ?Sized> Clone for BorrowedView<'a, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, C: Consumer + ?Sized> Copy for BorrowedView<'a, C> {}
+
+impl<'a, C: Consumer + ?Sized> BorrowedView<'a, C> {
+    /// Number of items in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+    /// Checks if the view is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+    /// Returns a reference to an item at `index` counting from the eldest one, if exists.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a C::Item> {
+        match index.checked_sub(self.left.len()) {
+            None => self.left.get(index),
+            Some(index) => self.right.get(index),
+        }
+    }
+    /// Pair of slices which contain, in order, the items of the view.
+    #[inline]
+    pub fn as_slices(&self) -> (&'a [C::Item], &'a [C::Item]) {
+        (self.left, self.right)
+    }
+    /// Returns a front-to-back iterator over items of the view.
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, C> {
+        self.left.iter().chain(self.right.iter())
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Index<usize> for BorrowedView<'a, C> {
+    type Output = C::Item;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.len(), index),
+        }
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> IntoIterator for BorrowedView<'a, C> {
+    type Item = &'a C::Item;
+    type IntoIter = Iter<'a, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The first contiguous slice of items that are going to be removed from the ring buffer.
+///
+/// Created by [`Consumer::pop_front_slice`].
+pub struct PoppedSlice<'a, C: Consumer + ?Sized> {
+    cons: &'a mut C,
+    len: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> PoppedSlice<'a, C> {
+    /// Items of the slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[C::Item] {
+        &self.cons.as_slices().0[..self.len]
+    }
+    /// Removes first `count` items of the slice from the ring buffer and drops them.
+    ///
+    /// If `count` is greater than the slice length then only items of the slice are removed.
+    ///
+    /// Returns the number of removed items.
+    pub fn commit(self, count: usize) -> usize {
+        self.cons.skip(usize::min(count, self.len))
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Deref for PoppedSlice<'a, C> {
+    type Target = [C::Item];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+/// Read transaction.
+///
+/// Created by [`Consumer::read_transaction`].
+pub struct ReadTxn<'a, C: Consumer + ?Sized> {
+    cons: &'a mut C,
+    consumed: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> ReadTxn<'a, C> {
+    /// Items that aren't marked as consumed yet, from the eldest to the most recent one.
+    pub fn as_slices(&self) -> (&[C::Item], &[C::Item]) {
+        let (left, right) = self.cons.as_slices();
+        match self.consumed.checked_sub(left.len()) {
+            None => (&left[self.consumed..], right),
+            Some(offset) => (&right[offset..], &[]),
+        }
+    }
+    /// Iterator over items that aren't marked as consumed yet.
+    pub fn iter(&self) -> Iter<'_, C> {
+        let (left, right) = self.as_slices();
+        left.iter().chain(right.iter())
+    }
+    /// Marks at most `count` items as consumed.
+    ///
+    /// Returns the number of items been marked.
+    pub fn consume(&mut self, count: usize) -> usize {
+        let count = usize::min(count, self.cons.occupied_len() - self.consumed);
+        self.consumed += count;
+        count
+    }
+    /// Number of items marked as consumed.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+    /// Removes items marked as consumed from the ring buffer and drops them.
+    ///
+    /// Returns the number of removed items.
+    pub fn commit(self) -> usize {
+        self.cons.skip(self.consumed)
+    }
+    /// Finishes transaction without removing any items.
+    ///
+    /// Same as dropping the transaction.
+    pub fn rollback(self) {}
+}
+
+/// Lending iterator over chunks of items that removes them from the ring buffer.
+///
+/// Created by [`Consumer::chunks`].
+#[cfg(feature = "alloc")]
+pub struct Chunks<'a, C: Consumer + ?Sized>
+where
+    C::Item: Copy,
+{
+    cons: &'a mut C,
+    size: usize,
+    taken: usize,
+    buf: Vec<C::Item>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, C: Consumer + ?Sized> Chunks<'a, C>
+where
+    C::Item: Copy,
+{
+    /// Returns the next chunk or `None` if there are no more items.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[C::Item]> {
+        let (left, right) = self.cons.as_slices();
+        let (left, right) = match self.taken.checked_sub(left.len()) {
+            None => (&left[self.taken..], right),
+            Some(offset) => (&right[offset..], &[][..]),
+        };
+        let chunk = if left.len() >= self.size || right.is_empty() {
+            &left[..usize::min(self.size, left.len())]
+        } else {
+            let tail = &right[..usize::min(self.size - left.len(), right.len())];
+            self.buf.clear();
+            self.buf.extend_from_slice(left);
+            self.buf.extend_from_slice(tail);
+            &self.buf
+        };
+        if chunk.is_empty() {
+            return None;
+        }
+        self.taken += chunk.len();
+        Some(chunk)
+    }
+    /// Removes items of all chunks returned so far from the ring buffer.
+    pub fn commit(&mut self) {
+        self.cons.skip(self.taken);
+        self.taken = 0;
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, C: Consumer + ?Sized> Drop for Chunks<'a, C>
+where
+    C::Item: Copy,
+{
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
+
 /// Iterator over ring buffer contents.
 ///
 /// *Please do not rely on actual type, it may change in future.*
 #[allow(type_alias_bounds)]
 pub type Iter<'a, C: Consumer> = Chain<slice::Iter<'a, C::Item>, slice::Iter<'a, C::Item>>;
 
+/// Iterator over ring buffer contents along with their positions.
+///
+/// *Please do not rely on actual type, it may change in future.*
+#[allow(type_alias_bounds)]
+pub type IterIndexed<'a, C: Consumer> = Enumerate<Iter<'a, C>>;
+
 /// Mutable iterator over ring buffer contents.
 ///
 /// *Please do not rely on actual type, it may change in future.*
@@ -396,6 +1120,10 @@ where
     unsafe fn advance_read_index(&self, count: usize) {
         self.base().advance_read_index(count)
     }
+    #[inline]
+    fn flush(&mut self) {
+        self.base_mut().flush()
+    }
 
     #[inline]
     fn occupied_slices(&self) -> (&[core::mem::MaybeUninit<Self::Item>], &[core::mem::MaybeUninit<Self::Item>]) {
@@ -430,11 +1158,24 @@ where
         self.base_mut().pop_slice(elems)
     }
 
+    #[inline]
+    fn pop_slice_volatile(&mut self, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().pop_slice_volatile(elems)
+    }
+
     #[inline]
     fn iter(&self) -> Iter<'_, Self> {
         self.base().iter()
     }
 
+    #[inline]
+    fn iter_indexed(&self) -> IterIndexed<'_, Self> {
+        self.base().iter_indexed()
+    }
+
     #[inline]
     fn iter_mut(&mut self) -> IterMut<'_, Self> {
         self.base_mut().iter_mut()
@@ -451,6 +1192,30 @@ where
     }
 }
 
+/// Writes ring buffer occupancy along with its items in order from the oldest one.
+///
+/// Counts are derived from the same snapshot of slices as the items, so they are consistent with each other.
+pub(crate) fn fmt_debug<C: Consumer + ?Sized>(this: &C, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    C::Item: fmt::Debug,
+{
+    struct Items<'a, T>(&'a [T], &'a [T]);
+    impl<T: fmt::Debug> fmt::Debug for Items<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.0.iter().chain(self.1)).finish()
+        }
+    }
+
+    let (left, right) = this.as_slices();
+    let occupied_len = left.len() + right.len();
+    f.debug_struct("RingBuffer")
+        .field("capacity", &this.capacity())
+        .field("occupied_len", &occupied_len)
+        .field("vacant_len", &(this.capacity().get() - occupied_len))
+        .field("items", &Items(left, right))
+        .finish()
+}
+
 macro_rules! impl_consumer_traits {
     ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? core::iter::IntoIterator for $type $(< $( $param ),+ >)? where Self: Sized {
@@ -461,6 +1226,33 @@ macro_rules! impl_consumer_traits {
             }
         }
 
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? core::fmt::Debug for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer,
+            <Self as $crate::traits::Observer>::Item: core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::traits::consumer::fmt_debug(self, f)
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? bytes::Buf for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer<Item = u8>,
+        {
+            fn remaining(&self) -> usize {
+                self.occupied_len()
+            }
+            fn chunk(&self) -> &[u8] {
+                self.as_slices().0
+            }
+            fn advance(&mut self, cnt: usize) {
+                assert!(cnt <= self.occupied_len(), "Cannot advance past the end of the ring buffer");
+                unsafe { self.advance_read_index(cnt) };
+            }
+        }
+
         #[cfg(feature = "std")]
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::Read for $type $(< $( $param ),+ >)?
         where
@@ -21,6 +21,14 @@ pub trait Observer {
     /// Index value is in range `0..(2 * capacity)`.
     fn write_index(&self) -> usize;
 
+    /// Read and write indices.
+    ///
+    /// Implementations may try to make the pair consistent, i.e. both indices were actual at the same moment.
+    /// This is a best-effort guarantee. Default implementation simply reads read index and then write index.
+    fn indices(&self) -> (usize, usize) {
+        (self.read_index(), self.write_index())
+    }
+
     /// Get slice between `start` and `end` indices.
     ///
     /// # Safety
@@ -35,6 +43,7 @@ pub trait Observer {
     /// # Safety
     ///
     /// There must not exist overlapping slices at the same time.
+    #[allow(clippy::mut_from_ref)]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]);
 
     /// Whether read end is held by consumer.
@@ -42,6 +51,14 @@ pub trait Observer {
     /// Whether write end is held by producer.
     fn write_is_held(&self) -> bool;
 
+    /// Whether the ring buffer storage is mirrored (see [`Storage::MIRRORED`](`crate::storage::Storage::MIRRORED`)).
+    ///
+    /// Slices of mirrored ring buffer never wrap, so the second slice is always empty.
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        false
+    }
+
     /// The number of items stored in the buffer.
     ///
     /// *Actual number may be greater or less than returned value due to concurring activity of producer or consumer respectively.*
@@ -73,6 +90,60 @@ pub trait Observer {
     fn is_full(&self) -> bool {
         self.vacant_len() == 0
     }
+
+    /// Checks if the number of items in the ring buffer is equal to its capacity.
+    ///
+    /// The same as [`Self::is_full`] but unambiguous in code that uses overwriting insertion.
+    ///
+    /// *The result may become irrelevant at any time because of concurring consumer activity.*
+    #[inline]
+    fn is_at_capacity(&self) -> bool {
+        self.is_full()
+    }
+
+    /// Total number of items ever pushed to the ring buffer.
+    ///
+    /// Unlike write index it doesn't wrap around.
+    /// Ring buffers that don't maintain counters return `0`.
+    #[cfg(feature = "metrics")]
+    fn total_written(&self) -> u64 {
+        0
+    }
+    /// Total number of items ever removed from the ring buffer.
+    ///
+    /// Unlike read index it doesn't wrap around.
+    /// Ring buffers that don't maintain counters return `0`.
+    #[cfg(feature = "metrics")]
+    fn total_read(&self) -> u64 {
+        0
+    }
+
+    /// Number of items pushed since the write index was equal to `write_index`.
+    ///
+    /// *Result is ambiguous if `2 * capacity` or more items were pushed since then, it wraps around modulo `2 * capacity`.*
+    fn items_pushed_since(&self, write_index: usize) -> usize {
+        let modulus = modulus(self);
+        (modulus.get() + self.write_index() - write_index) % modulus
+    }
+
+    /// Checks that `other` ring buffer has the same configuration (item type and capacity) as this one.
+    ///
+    /// Useful to check whether a ring buffer can be reused in place of another one.
+    #[inline]
+    fn same_config_as<O: Observer<Item = Self::Item> + ?Sized>(&self, other: &O) -> bool
+    where
+        Self: Sized,
+    {
+        self.capacity() == other.capacity()
+    }
+
+    /// Returns [`Debug`](core::fmt::Debug) adapter that shows capacity and occupancy of the ring buffer but not its items.
+    ///
+    /// Unlike [`Debug`](core::fmt::Debug) implementation of ring buffers and consumers it doesn't require `Item: Debug`.
+    #[inline]
+    fn debug_counts(&self) -> DebugCounts<'_, Self> {
+        DebugCounts { observer: self }
+    }
 }
 
 /// Trait used for delegating observer methods.
@@ -102,6 +173,11 @@ where
         self.base().write_index()
     }
 
+    #[inline]
+    fn indices(&self) -> (usize, usize) {
+        self.base().indices()
+    }
+
     #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.base().unsafe_slices(start, end)
@@ -119,6 +195,10 @@ where
     fn write_is_held(&self) -> bool {
         self.base().write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.base().is_mirrored()
+    }
 
     #[inline]
     fn occupied_len(&self) -> usize {
@@ -139,4 +219,55 @@ where
     fn is_full(&self) -> bool {
         self.base().is_full()
     }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.base().total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.base().total_read()
+    }
+}
+
+/// Writes short summary of the ring buffer state, e.g. `RingBuffer(3/256)` (occupied/capacity).
+pub(crate) fn fmt_summary<O: Observer + ?Sized>(this: &O, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "RingBuffer({}/{})", this.occupied_len(), this.capacity())
+}
+
+/// Writes ring buffer occupancy without its contents.
+///
+/// Used as [`Debug`](core::fmt::Debug) representation of wrappers that cannot safely read items (e.g. producers).
+pub(crate) fn fmt_debug_counts<O: Observer + ?Sized>(this: &O, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("RingBuffer")
+        .field("capacity", &this.capacity())
+        .field("occupied_len", &this.occupied_len())
+        .field("vacant_len", &this.vacant_len())
+        .finish()
+}
+
+/// Formats ring buffer occupancy without its contents.
+///
+/// Created by [`Observer::debug_counts`].
+pub struct DebugCounts<'a, O: Observer + ?Sized> {
+    observer: &'a O,
+}
+
+impl<O: Observer + ?Sized> core::fmt::Debug for DebugCounts<'_, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_debug_counts(self.observer, f)
+    }
+}
+
+macro_rules! impl_observer_traits {
+    ([$($param:tt)*] $type:ty) => {
+        impl<$($param)*> core::fmt::Display for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::traits::observer::fmt_summary(self, f)
+            }
+        }
+    };
 }
+pub(crate) use impl_observer_traits;
@@ -2,10 +2,17 @@ use super::{
     observer::{DelegateObserver, Observer},
     utils::modulus,
 };
+#[cfg(feature = "prefetch")]
+use crate::utils::prefetch_slice;
 #[cfg(feature = "std")]
 use crate::utils::slice_assume_init_mut;
 use crate::utils::write_slice;
-use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use crate::wrap::{BlockingWriter, RbWriter};
+use core::{
+    mem::{self, MaybeUninit},
+    ptr,
+};
 #[cfg(feature = "std")]
 use std::{
     cmp,
@@ -34,6 +41,13 @@ pub trait Producer: Observer {
         self.set_write_index((self.write_index() + count) % modulus(self));
     }
 
+    /// Makes pushed items visible to the consumer and fetches its changes.
+    ///
+    /// Does nothing by default. Wrappers that cache ring buffer indices (e.g. [`CachingProd`](`crate::CachingProd`)) override it.
+    /// Allows generic code to synchronize regardless of producer type.
+    #[inline]
+    fn flush(&mut self) {}
+
     /// Provides a direct access to the ring buffer vacant memory.
     ///
     /// Returns a pair of slices of uninitialized memory, the second one may be empty.
@@ -41,6 +55,20 @@ pub trait Producer: Observer {
         unsafe { self.unsafe_slices(self.write_index(), self.read_index() + self.capacity().get()) }
     }
 
+    /// Length of the first of [`Self::vacant_slices`].
+    ///
+    /// Computed from indices, mirrored storage has no second slice so the whole vacant length is returned for it.
+    #[inline]
+    fn vacant_len_contiguous(&self) -> usize {
+        let len = self.vacant_len();
+        if self.is_mirrored() {
+            len
+        } else {
+            let capacity = self.capacity().get();
+            usize::min(len, capacity - self.write_index() % capacity)
+        }
+    }
+
     /// Mutable version of [`Self::vacant_slices`].
     ///
     /// Vacant memory is uninitialized. Initialized items must be put starting from the beginning of first slice.
@@ -54,6 +82,47 @@ pub trait Producer: Observer {
         unsafe { self.unsafe_slices_mut(self.write_index(), self.read_index() + self.capacity().get()) }
     }
 
+    /// Provides a direct mutable access to the ring buffer vacant memory with the first slice split at `align` bytes boundary.
+    ///
+    /// Returns three slices: unaligned head, body which starts at address aligned to `align`, and tail that is the second vacant slice.
+    /// If there is no aligned address within the first vacant slice then the whole slice is returned as head.
+    ///
+    /// Slices must be filled in order, the same way as [`Self::vacant_slices_mut`].
+    ///
+    /// *Panics if `align` is not a power of two.*
+    fn vacant_slices_aligned_mut(
+        &mut self,
+        align: usize,
+    ) -> (
+        &mut [MaybeUninit<Self::Item>],
+        &mut [MaybeUninit<Self::Item>],
+        &mut [MaybeUninit<Self::Item>],
+    ) {
+        let (first, tail) = self.vacant_slices_mut();
+        let offset = first.as_ptr().align_offset(align);
+        let (head, body) = if offset <= first.len() {
+            first.split_at_mut(offset)
+        } else {
+            (first, &mut [][..])
+        };
+        (head, body, tail)
+    }
+
+    /// Checks that at least `additional` items can be pushed to the ring buffer.
+    ///
+    /// Returns `Err` containing number of vacant slots if there is not enough space.
+    ///
+    /// *Capacity of the ring buffer is fixed, so nothing is allocated.*
+    /// *The result may become irrelevant at any time because of concurring consumer activity, but vacant space may only grow.*
+    fn try_reserve(&self, additional: usize) -> Result<(), usize> {
+        let vacant = self.vacant_len();
+        if vacant >= additional {
+            Ok(())
+        } else {
+            Err(vacant)
+        }
+    }
+
     /// Appends an item to the ring buffer.
     ///
     /// If buffer is full returns an `Err` containing the item that hasn't been appended.
@@ -69,6 +138,20 @@ pub trait Producer: Observer {
         }
     }
 
+    /// Tries to append an item to the ring buffer up to `max_spins` times, calling [`core::hint::spin_loop`] between attempts.
+    ///
+    /// If buffer is still full after all attempts returns an `Err` containing the item that hasn't been appended.
+    fn try_push_spin(&mut self, mut elem: Self::Item, max_spins: usize) -> Result<(), Self::Item> {
+        for _ in 0..max_spins {
+            match self.try_push(elem) {
+                Ok(()) => return Ok(()),
+                Err(e) => elem = e,
+            }
+            core::hint::spin_loop();
+        }
+        self.try_push(elem)
+    }
+
     /// Appends items from an iterator to the ring buffer.
     /// Elements that haven't been added to the ring buffer remain in the iterator.
     ///
@@ -76,6 +159,21 @@ pub trait Producer: Observer {
     ///
     /// *Inserted items are committed to the ring buffer all at once in the end,*
     /// *e.g. when buffer is full or iterator has ended.*
+    ///
+    /// The iterator is not advanced past the last appended item,
+    /// so passing it by mutable reference allows to push the rest of items later.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 2>>::default();
+    /// let mut iter = 0..5;
+    ///
+    /// assert_eq!(rb.push_iter(&mut iter), 2);
+    /// assert!(iter.eq(2..5));
+    /// # }
+    /// ```
     fn push_iter<I: Iterator<Item = Self::Item>>(&mut self, mut iter: I) -> usize {
         let (left, right) = self.vacant_slices_mut();
         let mut count = 0;
@@ -90,6 +188,98 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends items from an [`ExactSizeIterator`] to the ring buffer.
+    ///
+    /// Same as [`Self::push_iter`] but the number of items to take is computed up front from `iter.len()`,
+    /// so each of vacant slices is filled in a tight loop without checking for remaining space on every item.
+    /// Items that don't fit remain in the iterator.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_exact_size_iter<I: ExactSizeIterator<Item = Self::Item>>(&mut self, mut iter: I) -> usize {
+        let (left, right) = self.vacant_slices_mut();
+        let count = iter.len().min(left.len() + right.len());
+        let left_count = count.min(left.len());
+        let (left, right) = (&mut left[..left_count], &mut right[..(count - left_count)]);
+        // Iterator may report wrong length, so count items actually taken.
+        let mut written = 0;
+        for (place, elem) in left.iter_mut().zip(iter.by_ref()) {
+            place.write(elem);
+            written += 1;
+        }
+        if written == left.len() {
+            for (place, elem) in right.iter_mut().zip(iter.by_ref()) {
+                place.write(elem);
+                written += 1;
+            }
+        }
+        unsafe { self.advance_write_index(written) };
+        written
+    }
+
+    /// Copies items referenced by an iterator to the ring buffer.
+    ///
+    /// Same as `self.push_iter(iter.copied())`.
+    /// Iterator is consumed until the ring buffer is full, write index is advanced once.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_refs<'a, I: Iterator<Item = &'a Self::Item>>(&mut self, mut iter: I) -> usize
+    where
+        Self::Item: Copy + 'a,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        let mut count = 0;
+        for (place, elem) in left.iter_mut().chain(right.iter_mut()).zip(&mut iter) {
+            place.write(*elem);
+            count += 1;
+        }
+        unsafe { self.advance_write_index(count) };
+        count
+    }
+
+    /// Appends all items from an array to the ring buffer or none of them.
+    ///
+    /// If there are less than `N` vacant slots then the ring buffer remains unchanged and the array is returned back in `Err`.
+    fn push_array<const N: usize>(&mut self, items: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        if self.vacant_len() < N {
+            return Err(items);
+        }
+        let items = mem::ManuallyDrop::new(items);
+        let (left, right) = self.vacant_slices_mut();
+        let left_len = usize::min(left.len(), N);
+        unsafe {
+            let src = items.as_ptr();
+            ptr::copy_nonoverlapping(src, left.as_mut_ptr() as *mut Self::Item, left_len);
+            ptr::copy_nonoverlapping(src.add(left_len), right.as_mut_ptr() as *mut Self::Item, N - left_len);
+            self.advance_write_index(N);
+        }
+        Ok(())
+    }
+
+    /// Appends all items from an iterator to the ring buffer or none of them.
+    ///
+    /// If the iterator has ended before the ring buffer became full then all items are committed and their count is returned.
+    /// Otherwise the items written so far are dropped, the ring buffer remains unchanged and `Err(())` is returned.
+    /// Note that in this case the iterator is consumed by `vacant_len + 1` items.
+    #[allow(clippy::result_unit_err)]
+    fn push_iter_transactional<I: Iterator<Item = Self::Item>>(&mut self, mut iter: I) -> Result<usize, ()> {
+        let (left, right) = self.vacant_slices_mut();
+        let vacant = left.len() + right.len();
+        let mut written = Written { left, right, count: 0 };
+        while written.count < vacant {
+            match iter.next() {
+                Some(elem) => written.push(elem),
+                None => break,
+            }
+        }
+        if written.count == vacant && iter.next().is_some() {
+            // Written items are dropped here.
+            return Err(());
+        }
+        let count = written.commit();
+        unsafe { self.advance_write_index(count) };
+        Ok(count)
+    }
+
     /// Appends items from slice to the ring buffer.
     ///
     /// Returns count of items been appended to the ring buffer.
@@ -117,6 +307,82 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends at most `count` default-constructed items to the ring buffer.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    ///
+    /// *Inserted items are committed to the ring buffer all at once in the end.*
+    /// *If `Default::default` panics then items created so far are dropped and the ring buffer remains unchanged.*
+    fn push_defaults(&mut self, count: usize) -> usize
+    where
+        Self::Item: Default,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        let count = usize::min(count, left.len() + right.len());
+        let mut written = Written { left, right, count: 0 };
+        for _ in 0..count {
+            written.push(Default::default());
+        }
+        let count = written.commit();
+        unsafe { self.advance_write_index(count) };
+        count
+    }
+
+    /// Appends items from slice to the ring buffer using volatile writes.
+    ///
+    /// Each item is written with [`core::ptr::write_volatile`], so writes are not elided or merged by compiler.
+    /// Useful when storage is read by hardware (e.g. DMA engine).
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_slice_volatile(&mut self, elems: &[Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        let count = usize::min(elems.len(), left.len() + right.len());
+        for (dst, src) in left.iter_mut().chain(right.iter_mut()).zip(&elems[..count]) {
+            unsafe { ptr::write_volatile(dst.as_mut_ptr(), *src) };
+        }
+        unsafe { self.advance_write_index(count) };
+        count
+    }
+
+    #[cfg(feature = "prefetch")]
+    /// Issues prefetch hint for at most `count` most recently written items.
+    ///
+    /// Prefetch is executed by the core running the producer, so it only brings items into the caches of that core
+    /// (and shared cache levels, if any). It doesn't warm up private caches of the core the consumer runs on.
+    /// Benefit, if any, depends on cache topology, so it should be measured.
+    /// Does nothing on architectures without stable prefetch intrinsics.
+    fn prefetch_written(&self, count: usize) {
+        let count = usize::min(count, self.occupied_len());
+        let write = self.write_index();
+        let modulus = modulus(self);
+        let (left, right) = unsafe { self.unsafe_slices((modulus.get() + write - count) % modulus, write) };
+        prefetch_slice(left);
+        prefetch_slice(right);
+    }
+
+    #[cfg(feature = "std")]
+    /// Wraps the producer into [`io::Write`] implementation which waits for vacant space instead of returning [`io::ErrorKind::WouldBlock`].
+    fn into_blocking_writer(self) -> BlockingWriter<Self>
+    where
+        Self: Producer<Item = u8> + Sized,
+    {
+        BlockingWriter::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    /// Wraps the producer into [`io::Write`] implementation.
+    ///
+    /// Unlike the producer itself the wrapper is a concrete writer type suitable for generic bounds and trait objects.
+    fn into_writer(self) -> RbWriter<Self>
+    where
+        Self: Producer<Item = u8> + Sized,
+    {
+        RbWriter::new(self)
+    }
+
     #[cfg(feature = "std")]
     /// Reads at most `count` bytes from `Read` instance and appends them to the ring buffer.
     /// If `count` is `None` then as much as possible bytes will be read.
@@ -126,6 +392,7 @@ pub trait Producer: Observer {
     /// + `Some(Ok(n))`: `read` succeeded. `n` is number of bytes been read. `n == 0` means that `read` also returned `0`.
     /// + `Some(Err(e))` `read` is failed and `e` is original error. In this case it is guaranteed that no items was read from the reader.
     ///   To achieve this we read only one contiguous slice at once. So this call may read less than `vacant_len` items in the buffer even if the reader is ready to provide more.
+    #[track_caller]
     fn read_from<S: Read>(&mut self, reader: &mut S, count: Option<usize>) -> Option<io::Result<usize>>
     where
         Self: Producer<Item = u8>,
@@ -152,6 +419,40 @@ pub trait Producer: Observer {
     }
 }
 
+/// Items written to vacant slices but not committed yet.
+///
+/// Written items are dropped when this is dropped.
+struct Written<'a, T> {
+    left: &'a mut [MaybeUninit<T>],
+    right: &'a mut [MaybeUninit<T>],
+    count: usize,
+}
+
+impl<'a, T> Written<'a, T> {
+    /// Write next item. There must be a vacant slot for it.
+    fn push(&mut self, elem: T) {
+        match self.count.checked_sub(self.left.len()) {
+            None => self.left[self.count].write(elem),
+            Some(i) => self.right[i].write(elem),
+        };
+        self.count += 1;
+    }
+
+    /// Forget written items without dropping them and return their count.
+    fn commit(mut self) -> usize {
+        mem::take(&mut self.count)
+    }
+}
+
+impl<'a, T> Drop for Written<'a, T> {
+    fn drop(&mut self) {
+        let (left, right) = (self.left.len().min(self.count), self.count.saturating_sub(self.left.len()));
+        for elem in self.left[..left].iter_mut().chain(self.right[..right].iter_mut()) {
+            unsafe { elem.assume_init_drop() };
+        }
+    }
+}
+
 /// Trait used for delegating consumer methods.
 pub trait DelegateProducer: DelegateObserver
 where
@@ -171,6 +472,10 @@ where
     unsafe fn advance_write_index(&self, count: usize) {
         self.base().advance_write_index(count)
     }
+    #[inline]
+    fn flush(&mut self) {
+        self.base_mut().flush()
+    }
 
     #[inline]
     fn vacant_slices(&self) -> (&[core::mem::MaybeUninit<Self::Item>], &[core::mem::MaybeUninit<Self::Item>]) {
@@ -192,6 +497,24 @@ where
         self.base_mut().push_iter(iter)
     }
 
+    #[inline]
+    fn push_exact_size_iter<I: ExactSizeIterator<Item = Self::Item>>(&mut self, iter: I) -> usize {
+        self.base_mut().push_exact_size_iter(iter)
+    }
+
+    #[inline]
+    fn push_refs<'a, I: Iterator<Item = &'a Self::Item>>(&mut self, iter: I) -> usize
+    where
+        Self::Item: Copy + 'a,
+    {
+        self.base_mut().push_refs(iter)
+    }
+
+    #[inline]
+    fn push_array<const N: usize>(&mut self, items: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        self.base_mut().push_array(items)
+    }
+
     #[inline]
     fn push_slice(&mut self, elems: &[Self::Item]) -> usize
     where
@@ -199,6 +522,14 @@ where
     {
         self.base_mut().push_slice(elems)
     }
+
+    #[inline]
+    fn push_slice_volatile(&mut self, elems: &[Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().push_slice_volatile(elems)
+    }
 }
 
 macro_rules! impl_producer_traits {
@@ -235,6 +566,23 @@ macro_rules! impl_producer_traits {
                 }
             }
          }
+
+        #[cfg(feature = "bytes")]
+        unsafe impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? bytes::BufMut for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Producer<Item = u8>,
+        {
+            fn remaining_mut(&self) -> usize {
+                self.vacant_len()
+            }
+            fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+                bytes::buf::UninitSlice::uninit(self.vacant_slices_mut().0)
+            }
+            unsafe fn advance_mut(&mut self, cnt: usize) {
+                assert!(cnt <= self.vacant_len(), "Cannot advance past the end of the ring buffer");
+                self.advance_write_index(cnt);
+            }
+        }
     };
  }
 pub(crate) use impl_producer_traits;
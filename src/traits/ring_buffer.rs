@@ -1,8 +1,15 @@
 use super::{
     consumer::{Consumer, DelegateConsumer},
     producer::{DelegateProducer, Producer},
+    utils::{modulus, Slots},
     Observer,
 };
+use crate::utils::{array_assume_init, uninit_array, write_slice};
+use core::{
+    iter::FusedIterator,
+    ops::{Bound, Range, RangeBounds},
+    ptr,
+};
 
 /// An abstract ring buffer that exclusively owns its data.
 pub trait RingBuffer: Observer + Consumer + Producer {
@@ -23,6 +30,37 @@ pub trait RingBuffer: Observer + Consumer + Producer {
     /// Must not be set to `false` while producer exists.
     unsafe fn hold_write(&self, flag: bool) -> bool;
 
+    /// Counts `count` items as read without moving read index.
+    ///
+    /// Accounts items removed from the middle of the ring buffer (e.g. by [`Self::drain`] or [`Self::retain`])
+    /// in read totals (`total_read` with `metrics` feature, or [`Counted`](`crate::wrap::Counted`) wrapper).
+    /// Does nothing for ring buffers that don't maintain counters.
+    #[inline]
+    fn add_total_read(&self, _count: usize) {}
+
+    /// Checks whether the next [`Self::push_overwrite`] call will remove the eldest item from the ring buffer.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 2>>::default();
+    /// let mut evicted = 0;
+    /// for i in 0..5 {
+    ///     if rb.next_push_overwrites() {
+    ///         evicted += 1;
+    ///     }
+    ///     rb.push_overwrite(i);
+    /// }
+    /// assert_eq!(evicted, 3);
+    /// assert!(rb.is_at_capacity());
+    /// # }
+    /// ```
+    #[inline]
+    fn next_push_overwrites(&self) -> bool {
+        self.is_full()
+    }
+
     /// Pushes an item to the ring buffer overwriting the latest item if the buffer is full.
     ///
     /// Returns overwritten item if overwriting took place.
@@ -58,6 +96,509 @@ pub trait RingBuffer: Observer + Consumer + Producer {
             elems
         });
     }
+
+    /// Same as [`Self::push_slice_overwrite`] but reports what happened to the items.
+    fn push_slice_overwrite_reporting(&mut self, elems: &[Self::Item]) -> OverwriteReport
+    where
+        Self::Item: Copy,
+    {
+        let truncated = elems.len().saturating_sub(self.capacity().get());
+        let elems = &elems[truncated..];
+        let overwritten = elems.len().saturating_sub(self.vacant_len());
+        self.skip(overwritten);
+        let written = self.push_slice(elems);
+        OverwriteReport {
+            written,
+            overwritten,
+            dropped_due_to_truncation: truncated,
+        }
+    }
+
+    /// Appends a frame of `M` items to the ring buffer if there is enough space for all of them.
+    ///
+    /// Returns `false` and leaves the ring buffer unchanged otherwise.
+    ///
+    /// Unlike [`Producer::push_slice`] the frame size is known at compile time, so copying can be fully unrolled for small `M`.
+    #[inline]
+    fn push_frame<const M: usize>(&mut self, frame: &[Self::Item; M]) -> bool
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        if left.len() + right.len() < M {
+            return false;
+        }
+        unsafe {
+            if left.len() >= M {
+                (left.as_mut_ptr() as *mut [Self::Item; M]).write(*frame);
+            } else {
+                let (head, tail) = frame.split_at(left.len());
+                write_slice(left, head);
+                write_slice(&mut right[..tail.len()], tail);
+            }
+            self.advance_write_index(M);
+        }
+        true
+    }
+
+    /// Removes a frame of exactly `M` items from the ring buffer.
+    ///
+    /// Returns `None` and leaves the ring buffer unchanged if there are less than `M` items.
+    ///
+    /// Unlike [`Consumer::pop_slice`] the frame size is known at compile time, so copying can be fully unrolled for small `M`.
+    #[inline]
+    fn pop_frame<const M: usize>(&mut self) -> Option<[Self::Item; M]>
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.as_slices();
+        if left.len() + right.len() < M {
+            return None;
+        }
+        let frame = if left.len() >= M {
+            unsafe { (left.as_ptr() as *const [Self::Item; M]).read() }
+        } else {
+            let mut frame = uninit_array::<Self::Item, M>();
+            write_slice(&mut frame[..left.len()], left);
+            write_slice(&mut frame[left.len()..], &right[..M - left.len()]);
+            unsafe { array_assume_init(frame) }
+        };
+        unsafe { self.advance_read_index(M) };
+        Some(frame)
+    }
+
+    /// Moves items to the beginning of the storage and resets indices.
+    ///
+    /// After this call read index is `0` and write index equals to the number of items.
+    /// If the ring buffer is empty then only indices are reset, no data is moved.
+    fn normalize(&mut self) {
+        let len = self.occupied_len();
+        let capacity = self.capacity().get();
+        let head = self.read_index() % capacity;
+        if len != 0 && head != 0 {
+            // Vacant slots are uninitialized, so it's safe to move them along with items.
+            unsafe { self.unsafe_slices_mut(0, capacity) }.0.rotate_left(head);
+        }
+        unsafe {
+            self.set_read_index(0);
+            self.set_write_index(len);
+        }
+    }
+
+    /// Moves items to the beginning of the storage only if they are wrapped around its end.
+    ///
+    /// Does nothing if items already occupy a contiguous region, so it is cheap to call it often.
+    /// Otherwise acts as [`Self::normalize`].
+    fn compact_if_wrapped(&mut self) {
+        if !self.occupied_slices().1.is_empty() {
+            self.normalize();
+        }
+    }
+
+    /// Makes occupied items a single contiguous slice and returns it.
+    ///
+    /// If items are wrapped around the end of the storage then they are moved to its beginning (see [`Self::normalize`]).
+    /// Otherwise (including empty ring buffer) nothing is moved.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 4>>::default();
+    /// rb.push_iter(0..3);
+    /// rb.skip(2);
+    /// rb.push_iter(3..6);
+    ///
+    /// assert_eq!(rb.make_contiguous(), [2, 3, 4, 5]);
+    /// # }
+    /// ```
+    fn make_contiguous(&mut self) -> &mut [Self::Item] {
+        self.compact_if_wrapped();
+        self.as_mut_slices().0
+    }
+
+    /// Calls `f` for each chunk of `n` items in the ring buffer from front to back.
+    ///
+    /// Chunks are taken directly from the ring buffer memory.
+    /// If some chunk crosses the end of the storage then all items are moved to make them contiguous first
+    /// (see [`Self::make_contiguous`]), that takes *O(len)* time.
+    /// The last chunk may contain less than `n` items.
+    ///
+    /// This method does not remove items out of the ring buffer.
+    ///
+    /// *Panics if `n` is zero.*
+    #[track_caller]
+    fn for_each_chunk<F: FnMut(&[Self::Item])>(&mut self, n: usize, f: F) {
+        assert!(n > 0, "Chunk size must be greater than zero");
+        if !self.occupied_slices().0.len().is_multiple_of(n) {
+            self.compact_if_wrapped();
+        }
+        let (left, right) = self.as_slices();
+        left.chunks(n).chain(right.chunks(n)).for_each(f);
+    }
+
+    /// Clones items in logical `range` (counting from the eldest item) and appends the clones to the ring buffer.
+    ///
+    /// If there is not enough vacant space then only the leading items of the range that fit are cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than its end or if the range end is greater than the number of items.
+    #[track_caller]
+    fn extend_from_within(&mut self, range: Range<usize>)
+    where
+        Self::Item: Clone,
+    {
+        let len = self.occupied_len();
+        assert!(range.start <= range.end, "Range start is greater than end");
+        assert!(range.end <= len, "Range end is out of bounds");
+        let count = usize::min(range.len(), self.vacant_len());
+        let slots = unsafe { Slots::new(self) };
+        for i in 0..count {
+            unsafe {
+                let elem = (*slots.get(range.start + i)).clone();
+                slots.get(len + i).write(elem);
+                // Commit each item separately to keep already cloned items if `clone` panics.
+                self.advance_write_index(1);
+            }
+        }
+    }
+
+    /// Overwrites occupied items starting at logical `offset` (counting from the eldest item) with items from slice.
+    ///
+    /// The written range must lie within occupied items, the ring buffer is never extended.
+    /// If it doesn't fit then the ring buffer remains unchanged and `false` is returned.
+    fn write_at(&mut self, offset: usize, elems: &[Self::Item]) -> bool
+    where
+        Self::Item: Copy,
+    {
+        match offset.checked_add(elems.len()) {
+            Some(end) if end <= self.occupied_len() => (),
+            _ => return false,
+        }
+        let (left, right) = self.as_mut_slices();
+        if offset < left.len() {
+            let (left_elems, right_elems) = elems.split_at(usize::min(elems.len(), left.len() - offset));
+            left[offset..][..left_elems.len()].copy_from_slice(left_elems);
+            right[..right_elems.len()].copy_from_slice(right_elems);
+        } else {
+            right[offset - left.len()..][..elems.len()].copy_from_slice(elems);
+        }
+        true
+    }
+
+    /// Applies `f` to each occupied item in order from the eldest one without removing them.
+    ///
+    /// Each of occupied slices is processed in a separate loop.
+    fn map_in_place<F: FnMut(&mut Self::Item)>(&mut self, mut f: F) {
+        let (left, right) = self.as_mut_slices();
+        left.iter_mut().for_each(&mut f);
+        right.iter_mut().for_each(f);
+    }
+
+    /// Removes items in logical `range` (counting from the eldest item) from the ring buffer and returns them as an iterator.
+    ///
+    /// Remaining items are shifted to close the gap. Either items before or after the range are moved, whichever are fewer.
+    /// Items that haven't been yielded by the iterator are dropped when it is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than its end or if the range end is greater than the number of items.
+    /// Also panics if an inclusive range end or an exclusive range start is `usize::MAX`.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..6);
+    ///
+    /// assert!(rb.drain(1..3).eq([1, 2]));
+    /// assert!(rb.iter().copied().eq([0, 3, 4, 5]));
+    /// # }
+    /// ```
+    #[track_caller]
+    fn drain<B: RangeBounds<usize>>(&mut self, range: B) -> Drain<'_, Self> {
+        let len = self.occupied_len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("drain range bound overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("drain range bound overflow"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "drain range start ({}) is greater than end ({})", start, end);
+        assert!(end <= len, "drain range end ({}) is out of bounds ({})", end, len);
+        Drain::new(self, start, end, len)
+    }
+
+    /// Removes the first item (counting from the eldest one) for which `pred` returns `true` and returns it.
+    ///
+    /// Remaining items are shifted to close the gap preserving their order.
+    /// Either items before or after the removed one are moved, whichever are fewer.
+    fn pop_find<P: FnMut(&Self::Item) -> bool>(&mut self, pred: P) -> Option<Self::Item> {
+        let index = self.position(pred)?;
+        self.drain(index..(index + 1)).next()
+    }
+
+    /// Removes all items from the ring buffer and returns them as an iterator from the eldest to the most recent one.
+    ///
+    /// Items are moved out, not cloned. The ring buffer is empty after the iterator is dropped,
+    /// items that haven't been yielded are dropped along with it.
+    ///
+    /// Same as `self.drain(..)`.
+    fn take_all(&mut self) -> Drain<'_, Self> {
+        self.drain(..)
+    }
+
+    /// Returns a cursor pointing to the eldest item that allows to modify items and remove them one by one.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..6);
+    ///
+    /// let mut cursor = rb.cursor_mut();
+    /// while let Some(x) = cursor.current() {
+    ///     if *x % 2 == 0 {
+    ///         cursor.remove_current();
+    ///     } else {
+    ///         *x *= 10;
+    ///         cursor.advance();
+    ///     }
+    /// }
+    /// assert!(rb.iter().copied().eq([10, 30, 50]));
+    /// # }
+    /// ```
+    fn cursor_mut(&mut self) -> CursorMut<'_, Self> {
+        CursorMut { rb: self, pos: 0 }
+    }
+
+    /// Retains only the items for which `f` returns `true`, preserving their order.
+    ///
+    /// Removed items are dropped, retained ones are shifted towards the eldest item.
+    /// If `f` panics then already removed items stay removed and all other items are retained.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 8>>::default();
+    /// rb.push_iter(0..6);
+    ///
+    /// rb.retain(|x| x % 3 != 0);
+    /// assert!(rb.iter().copied().eq([1, 2, 4, 5]));
+    /// # }
+    /// ```
+    fn retain<F: FnMut(&Self::Item) -> bool>(&mut self, mut f: F) {
+        /// Closes the gap of deleted items and updates write index even if `f` panics.
+        struct Guard<'a, R: RingBuffer + ?Sized> {
+            rb: &'a R,
+            slots: Slots<R::Item>,
+            processed: usize,
+            deleted: usize,
+            len: usize,
+        }
+        impl<'a, R: RingBuffer + ?Sized> Drop for Guard<'a, R> {
+            fn drop(&mut self) {
+                unsafe {
+                    self.slots
+                        .shift(self.processed, self.processed - self.deleted, self.len - self.processed);
+                    self.rb
+                        .set_write_index((self.rb.read_index() + self.len - self.deleted) % modulus(self.rb));
+                }
+                self.rb.add_total_read(self.deleted);
+            }
+        }
+
+        let len = self.occupied_len();
+        let mut guard = Guard {
+            slots: unsafe { Slots::new(self) },
+            rb: self,
+            processed: 0,
+            deleted: 0,
+            len,
+        };
+        while guard.processed < len {
+            let item = unsafe { guard.slots.get(guard.processed) };
+            if f(unsafe { &*item }) {
+                if guard.deleted > 0 {
+                    unsafe { ptr::copy_nonoverlapping(item, guard.slots.get(guard.processed - guard.deleted), 1) };
+                }
+                guard.processed += 1;
+            } else {
+                // Counters are updated before dropping, so the item isn't dropped again if its destructor panics.
+                guard.processed += 1;
+                guard.deleted += 1;
+                unsafe { ptr::drop_in_place(item) };
+            }
+        }
+    }
+}
+
+/// Closes the gap of `count` vacated slots at position `start` among `len` items.
+///
+/// Items before or after the gap are shifted, whichever are fewer, and indices are updated.
+///
+/// # Safety
+///
+/// Slots in the gap must be uninitialized, all other slots in `0..len` must be initialized.
+/// Write index value is ignored and set to the new end of items.
+unsafe fn close_gap<R: RingBuffer + ?Sized>(rb: &R, start: usize, count: usize, len: usize) {
+    let slots = Slots::new(rb);
+    let modulus = modulus(rb);
+    let read = rb.read_index();
+    if start < len - start - count {
+        slots.shift(0, count, start);
+        rb.set_read_index((read + count) % modulus);
+        rb.set_write_index((read + len) % modulus);
+    } else {
+        slots.shift(start + count, start, len - start - count);
+        rb.set_write_index((read + len - count) % modulus);
+    }
+    rb.add_total_read(count);
+}
+
+/// Result of [`RingBuffer::push_slice_overwrite_reporting`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OverwriteReport {
+    /// Number of items from the slice stored in the ring buffer.
+    pub written: usize,
+    /// Number of previously stored items removed to make room for new ones.
+    pub overwritten: usize,
+    /// Number of leading items from the slice that weren't stored because the slice is longer than capacity.
+    pub dropped_due_to_truncation: usize,
+}
+
+/// Iterator that removes a range of items from the ring buffer.
+///
+/// Created by [`RingBuffer::drain`].
+pub struct Drain<'a, R: RingBuffer + ?Sized> {
+    rb: &'a mut R,
+    slots: Slots<R::Item>,
+    start: usize,
+    front: usize,
+    back: usize,
+    end: usize,
+    len: usize,
+}
+
+impl<'a, R: RingBuffer + ?Sized> Drain<'a, R> {
+    fn new(rb: &'a mut R, start: usize, end: usize, len: usize) -> Self {
+        let slots = unsafe { Slots::new(rb) };
+        // Temporarily exclude the range and all items after it, so they are leaked instead of double-dropped if `Drain` is forgotten.
+        unsafe { rb.set_write_index((rb.read_index() + start) % modulus(rb)) };
+        Self {
+            rb,
+            slots,
+            start,
+            front: start,
+            back: end,
+            end,
+            len,
+        }
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> Iterator for Drain<'a, R> {
+    type Item = R::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let item = unsafe { self.slots.get(self.front).read() };
+            self.front += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remain = self.back - self.front;
+        (remain, Some(remain))
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> DoubleEndedIterator for Drain<'a, R> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { self.slots.get(self.back).read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> ExactSizeIterator for Drain<'a, R> {}
+impl<'a, R: RingBuffer + ?Sized> FusedIterator for Drain<'a, R> {}
+
+impl<'a, R: RingBuffer + ?Sized> Drop for Drain<'a, R> {
+    fn drop(&mut self) {
+        while self.front < self.back {
+            let pos = self.front;
+            self.front += 1;
+            unsafe { ptr::drop_in_place(self.slots.get(pos)) };
+        }
+        unsafe { close_gap(self.rb, self.start, self.end - self.start, self.len) };
+    }
+}
+
+/// Cursor over ring buffer items that allows to remove the current item.
+///
+/// Created by [`RingBuffer::cursor_mut`].
+pub struct CursorMut<'a, R: RingBuffer + ?Sized> {
+    rb: &'a mut R,
+    pos: usize,
+}
+
+impl<'a, R: RingBuffer + ?Sized> CursorMut<'a, R> {
+    /// Position of the current item counting from the eldest one.
+    pub fn index(&self) -> usize {
+        self.pos
+    }
+
+    /// Mutable reference to the current item or `None` if the cursor is past the last item.
+    pub fn current(&mut self) -> Option<&mut R::Item> {
+        if self.pos < self.rb.occupied_len() {
+            Some(unsafe { &mut *Slots::new(self.rb).get(self.pos) })
+        } else {
+            None
+        }
+    }
+
+    /// Moves the cursor to the next item.
+    ///
+    /// Returns `false` if the cursor is already past the last item.
+    pub fn advance(&mut self) -> bool {
+        if self.pos < self.rb.occupied_len() {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the current item from the ring buffer and returns it.
+    ///
+    /// Remaining items are shifted to close the gap, the cursor then points to the item next to the removed one.
+    pub fn remove_current(&mut self) -> Option<R::Item> {
+        let len = self.rb.occupied_len();
+        if self.pos < len {
+            unsafe {
+                let item = Slots::new(self.rb).get(self.pos).read();
+                close_gap(self.rb, self.pos, 1, len);
+                Some(item)
+            }
+        } else {
+            None
+        }
+    }
 }
 
 /// Trait used for delegating owning ring buffer methods.
@@ -77,6 +618,10 @@ where
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.base().hold_write(flag)
     }
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.base().add_total_read(count)
+    }
 
     #[inline]
     fn push_overwrite(&mut self, elem: Self::Item) -> Option<Self::Item> {
@@ -95,4 +640,33 @@ where
     {
         self.base_mut().push_slice_overwrite(elems)
     }
+
+    #[inline]
+    fn push_slice_overwrite_reporting(&mut self, elems: &[Self::Item]) -> OverwriteReport
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().push_slice_overwrite_reporting(elems)
+    }
+
+    #[inline]
+    fn push_frame<const M: usize>(&mut self, frame: &[Self::Item; M]) -> bool
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().push_frame(frame)
+    }
+
+    #[inline]
+    fn pop_frame<const M: usize>(&mut self) -> Option<[Self::Item; M]>
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().pop_frame()
+    }
+
+    #[inline]
+    fn map_in_place<F: FnMut(&mut Self::Item)>(&mut self, f: F) {
+        self.base_mut().map_in_place(f)
+    }
 }
@@ -1,5 +1,5 @@
 use super::Observer;
-use core::num::NonZeroUsize;
+use core::{mem::MaybeUninit, num::NonZeroUsize, ptr};
 
 /// Trait that should be implemented by ring buffer wrappers.
 ///
@@ -20,3 +20,53 @@ pub trait Based {
 pub fn modulus<O: Observer + ?Sized>(this: &O) -> NonZeroUsize {
     unsafe { NonZeroUsize::new_unchecked(2 * this.capacity().get()) }
 }
+
+/// Raw access to ring buffer slots by position counting from the read index.
+///
+/// Positions are taken relative to the read index at the moment of creation.
+pub struct Slots<T> {
+    ptr: *mut MaybeUninit<T>,
+    capacity: usize,
+    head: usize,
+}
+
+impl<T> Slots<T> {
+    /// # Safety
+    ///
+    /// There must be no other references to the ring buffer storage while slots are accessed.
+    pub unsafe fn new<O: Observer<Item = T> + ?Sized>(this: &O) -> Self {
+        let capacity = this.capacity().get();
+        Self {
+            ptr: this.unsafe_slices_mut(0, capacity).0.as_mut_ptr(),
+            capacity,
+            head: this.read_index() % capacity,
+        }
+    }
+
+    /// Pointer to the slot at `pos`.
+    ///
+    /// # Safety
+    ///
+    /// `pos` must be less than capacity.
+    #[inline]
+    pub unsafe fn get(&self, pos: usize) -> *mut T {
+        self.ptr.add((self.head + pos) % self.capacity) as *mut T
+    }
+
+    /// Moves `count` items starting at `src` position to `dst` position. Ranges may overlap.
+    ///
+    /// # Safety
+    ///
+    /// Both ranges must be within capacity, source slots must be initialized.
+    pub unsafe fn shift(&self, src: usize, dst: usize, count: usize) {
+        if dst < src {
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.get(src + i), self.get(dst + i), 1);
+            }
+        } else if dst > src {
+            for i in (0..count).rev() {
+                ptr::copy_nonoverlapping(self.get(src + i), self.get(dst + i), 1);
+            }
+        }
+    }
+}
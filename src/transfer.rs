@@ -1,28 +1,68 @@
-use crate::{consumer::Consumer, producer::Producer};
+use crate::{consumer::Consumer, producer::Producer, utils::move_uninit_slice};
+use core::mem;
 
 /// Moves at most `count` items from the `src` consumer to the `dst` producer.
 ///
 /// Consumer and producer may be of different buffers as well as of the same one.
 /// `count` is the number of items being moved, if `None` - as much as possible items will be moved.
 ///
+/// Items are moved slice-wise, so the number of items being moved is `min(src.occupied_len(), dst.vacant_len(), count)`.
+///
 /// Returns number of items been moved.
 pub fn transfer<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P, count: Option<usize>) -> usize {
-    let (src_left, src_right) = src.occupied_slices();
-    let (dst_left, dst_right) = dst.vacant_slices_mut();
-    let src_iter = src_left.iter().chain(src_right.iter());
-    let dst_iter = dst_left.iter_mut().chain(dst_right.iter_mut());
+    let (mut src_slice, mut src_next) = src.occupied_slices();
+    let (mut dst_slice, mut dst_next) = dst.vacant_slices_mut();
+    let mut remaining = (src_slice.len() + src_next.len()).min(dst_slice.len() + dst_next.len());
+    if let Some(count) = count {
+        remaining = remaining.min(count);
+    }
 
-    let mut actual_count = 0;
-    for (src_elem, dst_place) in src_iter.zip(dst_iter) {
-        if let Some(count) = count {
-            if actual_count >= count {
-                break;
-            }
+    let actual_count = remaining;
+    // Each side consists of at most two slices, so there are at most three contiguous moves.
+    while remaining > 0 {
+        if src_slice.is_empty() {
+            src_slice = mem::take(&mut src_next);
+        }
+        if dst_slice.is_empty() {
+            dst_slice = mem::take(&mut dst_next);
         }
-        unsafe { dst_place.write(src_elem.as_ptr().read()) };
-        actual_count += 1;
+        let n = remaining.min(src_slice.len()).min(dst_slice.len());
+        let (src_part, src_rest) = src_slice.split_at(n);
+        let (dst_part, dst_rest) = mem::take(&mut dst_slice).split_at_mut(n);
+        move_uninit_slice(dst_part, src_part);
+        src_slice = src_rest;
+        dst_slice = dst_rest;
+        remaining -= n;
     }
     unsafe { src.advance_read_index(actual_count) };
     unsafe { dst.advance_write_index(actual_count) };
     actual_count
 }
+
+/// Moves all items from the `src` consumer to the `dst` producer until `src` is empty or `dst` is full.
+///
+/// Same as `transfer(src, dst, None)`.
+///
+/// Returns number of items been moved.
+pub fn transfer_all<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P) -> usize {
+    transfer(src, dst, None)
+}
+
+/// Pushes a clone of `item` to each producer in `prods` that has a vacant slot.
+///
+/// Full producers are skipped and `item` is not cloned for them.
+///
+/// Returns number of producers the item was pushed to.
+pub fn broadcast<T: Clone, P: Producer<Item = T>>(item: &T, prods: &mut [&mut P]) -> usize {
+    let mut count = 0;
+    for prod in prods.iter_mut() {
+        if !prod.is_full() {
+            unsafe {
+                prod.vacant_slices_mut().0.get_unchecked_mut(0).write(item.clone());
+                prod.advance_write_index(1);
+            }
+            count += 1;
+        }
+    }
+    count
+}
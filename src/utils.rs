@@ -44,6 +44,15 @@ pub fn array_to_uninit<T, const N: usize>(value: [T; N]) -> [MaybeUninit<T>; N]
     unsafe { ptr.read() }
 }
 
+/// # Safety
+///
+/// All items of the array must be initialized.
+pub unsafe fn array_assume_init<T, const N: usize>(value: [MaybeUninit<T>; N]) -> [T; N] {
+    let value = mem::ManuallyDrop::new(value);
+    let ptr = &value as *const _ as *const [T; N];
+    ptr.read()
+}
+
 #[cfg(feature = "alloc")]
 pub fn vec_to_uninit<T>(value: Vec<T>) -> Vec<MaybeUninit<T>> {
     let value = mem::ManuallyDrop::new(value);
@@ -57,3 +66,23 @@ pub fn boxed_slice_to_uninit<T>(value: Box<[T]>) -> Box<[MaybeUninit<T>]> {
     let ptr = &value as *const _ as *const Box<[MaybeUninit<T>]>;
     unsafe { ptr.read() }
 }
+
+/// Hints CPU to fetch memory occupied by `slice` into cache.
+///
+/// Does nothing on architectures without stable prefetch intrinsics.
+#[cfg(feature = "prefetch")]
+#[inline]
+pub fn prefetch_slice<T>(slice: &[T]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        const CACHE_LINE: usize = 64;
+
+        let ptr = slice.as_ptr().cast::<i8>();
+        for offset in (0..core::mem::size_of_val(slice)).step_by(CACHE_LINE) {
+            unsafe { _mm_prefetch::<_MM_HINT_T0>(ptr.add(offset)) };
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = slice;
+}
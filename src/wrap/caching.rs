@@ -7,8 +7,8 @@ use crate::{
     rb::RbRef,
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::{impl_observer_traits, Observer},
         producer::{impl_producer_traits, Producer},
-        Observer,
     },
 };
 use core::{mem::MaybeUninit, num::NonZeroUsize};
@@ -27,6 +27,7 @@ impl<R: RbRef, const P: bool, const C: bool> Caching<R, P, C> {
     /// Create a new ring buffer cached wrapper.
     ///
     /// Panics if wrapper with matching rights already exists.
+    #[track_caller]
     pub fn new(rb: R) -> Self {
         Self { frozen: Frozen::new(rb) }
     }
@@ -87,9 +88,11 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Caching<R, P, C> {
         self.frozen.write_index()
     }
 
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.frozen.unsafe_slices(start, end)
     }
+    #[inline]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.frozen.unsafe_slices_mut(start, end)
     }
@@ -102,6 +105,21 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Caching<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.frozen.write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.frozen.is_mirrored()
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.frozen.total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.frozen.total_read()
+    }
 }
 
 impl<R: RbRef> Producer for CachingProd<R> {
@@ -111,15 +129,26 @@ impl<R: RbRef> Producer for CachingProd<R> {
         self.frozen.commit();
     }
 
+    #[inline]
+    fn flush(&mut self) {
+        self.frozen.sync();
+    }
+
+    #[inline]
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
+        // Fetch read index only when cached one tells that there is no space left.
         if self.frozen.is_full() {
             self.frozen.fetch();
+            if self.frozen.is_full() {
+                return Err(elem);
+            }
         }
-        let r = self.frozen.try_push(elem);
-        if r.is_ok() {
-            self.frozen.commit();
+        unsafe {
+            self.frozen.vacant_slices_mut().0.get_unchecked_mut(0).write(elem);
+            self.frozen.advance_write_index(1);
         }
-        r
+        self.frozen.commit();
+        Ok(())
     }
 }
 
@@ -130,17 +159,36 @@ impl<R: RbRef> Consumer for CachingCons<R> {
         self.frozen.commit();
     }
 
+    #[inline]
+    fn flush(&mut self) {
+        self.frozen.sync();
+    }
+
+    #[inline]
     fn try_pop(&mut self) -> Option<<Self as Observer>::Item> {
+        // Fetch write index only when cached one tells that there are no items left.
         if self.frozen.is_empty() {
             self.frozen.fetch();
+            if self.frozen.is_empty() {
+                return None;
+            }
         }
-        let r = self.frozen.try_pop();
-        if r.is_some() {
-            self.frozen.commit();
-        }
-        r
+        let elem = unsafe {
+            let elem = self.frozen.occupied_slices().0.get_unchecked(0).assume_init_read();
+            self.frozen.advance_read_index(1);
+            elem
+        };
+        self.frozen.commit();
+        Some(elem)
     }
 }
 
+impl_observer_traits!([R: RbRef, const P: bool, const C: bool] Caching<R, P, C>);
 impl_producer_traits!(CachingProd<R: RbRef>);
 impl_consumer_traits!(CachingCons<R: RbRef>);
+
+impl<R: RbRef> core::fmt::Debug for CachingProd<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::traits::observer::fmt_debug_counts(self, f)
+    }
+}
@@ -0,0 +1,116 @@
+//! Throughput counting wrapper.
+//!
+//! Ring buffer indices are modular and do not track how many items have ever passed through it.
+//! This wrapper maintains lifetime totals of pushed and popped items.
+
+use crate::traits::{observer::DelegateObserver, Based, Consumer, Observer, Producer, RingBuffer};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicU64, Ordering};
+
+/// Wrapper that counts total number of items pushed to and popped from the ring buffer.
+///
+/// Counters have the same semantics as `total_written`/`total_read` of `metrics` feature
+/// but count only items passed through this wrapper:
+/// they are incremented each time write or read index is advanced,
+/// items discarded by overwriting or removed from the middle of the ring buffer are counted as popped.
+pub struct Counted<B: Observer> {
+    base: B,
+    pushed: AtomicU64,
+    popped: AtomicU64,
+}
+
+impl<B: Observer> Counted<B> {
+    /// Wrap `base` into counter with zero totals.
+    pub fn new(base: B) -> Self {
+        Self {
+            base,
+            pushed: AtomicU64::new(0),
+            popped: AtomicU64::new(0),
+        }
+    }
+
+    /// Total number of items pushed through this wrapper.
+    pub fn total_pushed(&self) -> u64 {
+        self.pushed.load(Ordering::Relaxed)
+    }
+    /// Total number of items popped through this wrapper.
+    pub fn total_popped(&self) -> u64 {
+        self.popped.load(Ordering::Relaxed)
+    }
+
+    /// Reset both counters to zero.
+    pub fn reset_totals(&mut self) {
+        *self.pushed.get_mut() = 0;
+        *self.popped.get_mut() = 0;
+    }
+
+    /// Get the underlying wrapper.
+    pub fn into_inner(self) -> B {
+        self.base
+    }
+}
+
+impl<B: Observer> Based for Counted<B> {
+    type Base = B;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<B: Observer> DelegateObserver for Counted<B> {}
+
+impl<B: Producer> Producer for Counted<B> {
+    #[inline]
+    unsafe fn set_write_index(&self, value: usize) {
+        self.base.set_write_index(value);
+    }
+    #[inline]
+    unsafe fn advance_write_index(&self, count: usize) {
+        self.base.advance_write_index(count);
+        self.pushed.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+impl<B: Consumer> Consumer for Counted<B> {
+    #[inline]
+    unsafe fn set_read_index(&self, value: usize) {
+        self.base.set_read_index(value);
+    }
+    #[inline]
+    unsafe fn advance_read_index(&self, count: usize) {
+        self.base.advance_read_index(count);
+        self.popped.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+impl<B: RingBuffer> RingBuffer for Counted<B> {
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        self.base.hold_read(flag)
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.base.hold_write(flag)
+    }
+    #[inline]
+    fn add_total_read(&self, count: usize) {
+        self.base.add_total_read(count);
+        self.popped.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+impl<B: Observer> AsRef<Self> for Counted<B> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<B: Observer> AsMut<Self> for Counted<B> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
@@ -7,10 +7,13 @@ use crate::{
     rb::RbRef,
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::{impl_observer_traits, Observer},
         producer::{impl_producer_traits, Producer},
-        Observer, RingBuffer,
+        RingBuffer,
     },
 };
+#[cfg(feature = "alloc")]
+use alloc::sync::{Arc, Weak};
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
@@ -35,10 +38,49 @@ impl<R: RbRef> Clone for Obs<R> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> Obs<Arc<B>> {
+    /// Create an observer that doesn't keep the ring buffer alive.
+    pub fn downgrade(&self) -> WeakObs<B> {
+        WeakObs {
+            rb: Arc::downgrade(&self.rb),
+        }
+    }
+}
+
+/// Observer of a ring buffer that holds a weak reference to it.
+///
+/// Doesn't prevent the ring buffer from being dropped.
+#[cfg(feature = "alloc")]
+pub struct WeakObs<B: RingBuffer + ?Sized> {
+    rb: Weak<B>,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> WeakObs<B> {
+    /// Create a new weak observer of the ring buffer.
+    pub fn new(rb: &Arc<B>) -> Self {
+        Self { rb: Arc::downgrade(rb) }
+    }
+
+    /// Get observer if the ring buffer is still alive.
+    pub fn upgrade(&self) -> Option<Obs<Arc<B>>> {
+        self.rb.upgrade().map(|rb| Obs { rb })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> Clone for WeakObs<B> {
+    fn clone(&self) -> Self {
+        Self { rb: self.rb.clone() }
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Direct<R, P, C> {
     /// Create a new ring buffer direct wrapper.
     ///
     /// Panics if wrapper with matching rights already exists.
+    #[track_caller]
     pub fn new(rb: R) -> Self {
         if P {
             assert!(!unsafe { rb.rb().hold_write(true) });
@@ -73,6 +115,36 @@ impl<R: RbRef, const P: bool, const C: bool> Direct<R, P, C> {
     }
 }
 
+impl<R: RbRef> Prod<R> {
+    /// Busy-wait until at least `count` slots are vacant.
+    ///
+    /// Spins on the ring buffer indices using [`core::hint::spin_loop`] without any syscalls or allocations,
+    /// so it is suitable for real-time threads.
+    ///
+    /// *Panics if `count` is greater than capacity.*
+    pub fn busy_wait_vacant(&self, count: usize) {
+        assert!(count <= self.capacity().get());
+        while self.vacant_len() < count {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+impl<R: RbRef> Cons<R> {
+    /// Busy-wait until at least `count` items are occupied.
+    ///
+    /// Spins on the ring buffer indices using [`core::hint::spin_loop`] without any syscalls or allocations,
+    /// so it is suitable for real-time threads.
+    ///
+    /// *Panics if `count` is greater than capacity.*
+    pub fn busy_wait_occupied(&self, count: usize) {
+        assert!(count <= self.capacity().get());
+        while self.occupied_len() < count {
+            core::hint::spin_loop();
+        }
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Wrap for Direct<R, P, C> {
     type RbRef = R;
     fn rb_ref(&self) -> &R {
@@ -114,6 +186,10 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
         self.rb().write_index()
     }
     #[inline]
+    fn indices(&self) -> (usize, usize) {
+        self.rb().indices()
+    }
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
@@ -129,6 +205,29 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.rb().write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.rb().is_mirrored()
+    }
+    #[inline]
+    fn occupied_len(&self) -> usize {
+        self.rb().occupied_len()
+    }
+    #[inline]
+    fn vacant_len(&self) -> usize {
+        self.rb().vacant_len()
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.rb().total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.rb().total_read()
+    }
 }
 
 impl<R: RbRef> Producer for Prod<R> {
@@ -136,6 +235,10 @@ impl<R: RbRef> Producer for Prod<R> {
     unsafe fn set_write_index(&self, value: usize) {
         self.rb().set_write_index(value)
     }
+    #[inline]
+    unsafe fn advance_write_index(&self, count: usize) {
+        self.rb().advance_write_index(count)
+    }
 }
 
 impl<R: RbRef> Consumer for Cons<R> {
@@ -143,6 +246,10 @@ impl<R: RbRef> Consumer for Cons<R> {
     unsafe fn set_read_index(&self, value: usize) {
         self.rb().set_read_index(value)
     }
+    #[inline]
+    unsafe fn advance_read_index(&self, count: usize) {
+        self.rb().advance_read_index(count)
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> Drop for Direct<R, P, C> {
@@ -151,5 +258,17 @@ impl<R: RbRef, const P: bool, const C: bool> Drop for Direct<R, P, C> {
     }
 }
 
+impl_observer_traits!([R: RbRef, const P: bool, const C: bool] Direct<R, P, C>);
 impl_producer_traits!(Prod<R: RbRef>);
 impl_consumer_traits!(Cons<R: RbRef>);
+
+impl<R: RbRef> core::fmt::Debug for Prod<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::traits::observer::fmt_debug_counts(self, f)
+    }
+}
+impl<R: RbRef> core::fmt::Debug for Obs<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::traits::observer::fmt_debug_counts(self, f)
+    }
+}
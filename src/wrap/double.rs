@@ -0,0 +1,185 @@
+//! Double-buffered producer and consumer.
+//!
+//! Producer writes into one of two ring buffers and can switch to the other one at any time.
+//! Consumer reads the remaining items from the previous ring buffer before following the producer,
+//! so the order of items is preserved across switches.
+
+use super::direct::{Cons, Prod};
+use crate::{
+    rb::SharedRb,
+    storage::Storage,
+    traits::{consumer::DelegateConsumer, observer::DelegateObserver, producer::DelegateProducer, Based, Observer, Split},
+};
+use alloc::sync::Arc;
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicUsize, Ordering};
+
+/// Pair of ring buffers switched by the producer.
+///
+/// Split it to get [`DoubleBufferedProd`] and [`DoubleBufferedCons`].
+pub struct DoubleBuffered<S: Storage> {
+    rbs: [SharedRb<S>; 2],
+}
+
+/// Number of switches made by each side.
+///
+/// Index of the ring buffer in use is the number of switches modulo 2.
+/// Write epoch is only changed by the producer and read epoch - by the consumer.
+struct Epochs {
+    write: AtomicUsize,
+    read: AtomicUsize,
+}
+
+impl<S: Storage> DoubleBuffered<S> {
+    /// Create a double buffer. Producer starts writing into `first` one.
+    ///
+    /// Existing items of both ring buffers are read by the consumer starting from `first`.
+    pub fn new(first: SharedRb<S>, second: SharedRb<S>) -> Self {
+        Self { rbs: [first, second] }
+    }
+}
+
+impl<S: Storage> Split for DoubleBuffered<S> {
+    type Prod = DoubleBufferedProd<S>;
+    type Cons = DoubleBufferedCons<S>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        let [first, second] = self.rbs.map(Arc::new);
+        let epochs = Arc::new(Epochs {
+            write: AtomicUsize::new(0),
+            read: AtomicUsize::new(0),
+        });
+        (
+            DoubleBufferedProd {
+                prods: [Prod::new(first.clone()), Prod::new(second.clone())],
+                epochs: epochs.clone(),
+                epoch: 0,
+            },
+            DoubleBufferedCons {
+                conss: [Cons::new(first), Cons::new(second)],
+                epochs,
+            },
+        )
+    }
+}
+
+/// Producer of [`DoubleBuffered`].
+///
+/// Writes into the active ring buffer, all producer methods refer to it.
+pub struct DoubleBufferedProd<S: Storage> {
+    prods: [Prod<Arc<SharedRb<S>>>; 2],
+    epochs: Arc<Epochs>,
+    epoch: usize,
+}
+
+/// Consumer of [`DoubleBuffered`].
+///
+/// Reads from the ring buffer the producer has left until it is empty and then switches to the active one.
+/// All consumer methods refer to the ring buffer being read.
+///
+/// Switching is made only by methods taking `&mut self`.
+/// Observer methods refer to the ring buffer that will be read next, but don't switch to it.
+pub struct DoubleBufferedCons<S: Storage> {
+    conss: [Cons<Arc<SharedRb<S>>>; 2],
+    epochs: Arc<Epochs>,
+}
+
+impl<S: Storage> DoubleBufferedProd<S> {
+    /// Switch writing to the other ring buffer.
+    ///
+    /// Items already written to the current ring buffer are still read by the consumer before the new ones.
+    ///
+    /// Fails and returns `false` if the consumer hasn't drained the other ring buffer since the previous switch yet.
+    pub fn swap(&mut self) -> bool {
+        if self.epochs.read.load(Ordering::Acquire) != self.epoch {
+            return false;
+        }
+        self.epoch += 1;
+        self.epochs.write.store(self.epoch, Ordering::Release);
+        true
+    }
+
+    /// Number of switches made.
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+impl<S: Storage> DoubleBufferedCons<S> {
+    /// Number of switches made.
+    pub fn epoch(&self) -> usize {
+        self.epochs.read.load(Ordering::Relaxed)
+    }
+
+    /// Epoch of the ring buffer to read from, it is the next one if the current one is drained and the producer has switched.
+    fn next_epoch(&self) -> usize {
+        let epoch = self.epochs.read.load(Ordering::Relaxed);
+        let cons = &self.conss[epoch % 2];
+        // Items written before switching are visible after loading write epoch, so emptiness is checked again.
+        if cons.is_empty() && self.epochs.write.load(Ordering::Acquire) != epoch && cons.is_empty() {
+            epoch + 1
+        } else {
+            epoch
+        }
+    }
+
+    /// Follow the producer if the current ring buffer is drained and get the index of the ring buffer to read from.
+    fn switch(&mut self) -> usize {
+        let epoch = self.next_epoch();
+        if epoch != self.epochs.read.load(Ordering::Relaxed) {
+            self.epochs.read.store(epoch, Ordering::Release);
+        }
+        epoch % 2
+    }
+}
+
+impl<S: Storage> Based for DoubleBufferedProd<S> {
+    type Base = Prod<Arc<SharedRb<S>>>;
+    fn base(&self) -> &Self::Base {
+        &self.prods[self.epoch % 2]
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.prods[self.epoch % 2]
+    }
+}
+
+impl<S: Storage> Based for DoubleBufferedCons<S> {
+    type Base = Cons<Arc<SharedRb<S>>>;
+    fn base(&self) -> &Self::Base {
+        &self.conss[self.next_epoch() % 2]
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        let index = self.switch();
+        &mut self.conss[index]
+    }
+}
+
+impl<S: Storage> DelegateObserver for DoubleBufferedProd<S> {}
+impl<S: Storage> DelegateProducer for DoubleBufferedProd<S> {}
+
+impl<S: Storage> DelegateObserver for DoubleBufferedCons<S> {}
+impl<S: Storage> DelegateConsumer for DoubleBufferedCons<S> {}
+
+impl<S: Storage> AsRef<Self> for DoubleBufferedProd<S> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<S: Storage> AsMut<Self> for DoubleBufferedProd<S> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<S: Storage> AsRef<Self> for DoubleBufferedCons<S> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<S: Storage> AsMut<Self> for DoubleBufferedCons<S> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
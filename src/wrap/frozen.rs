@@ -7,8 +7,9 @@ use crate::{
     rb::RbRef,
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::{impl_observer_traits, Observer},
         producer::{impl_producer_traits, Producer},
-        Observer, RingBuffer,
+        RingBuffer,
     },
 };
 use core::{
@@ -41,6 +42,7 @@ impl<R: RbRef, const P: bool, const C: bool> Frozen<R, P, C> {
     /// Create a new ring buffer frozen wrapper.
     ///
     /// Panics if wrapper with matching rights already exists.
+    #[track_caller]
     pub fn new(rb: R) -> Self {
         if P {
             assert!(!unsafe { rb.rb().hold_write(true) });
@@ -109,12 +111,25 @@ impl<R: RbRef, const P: bool, const C: bool> AsMut<Self> for Frozen<R, P, C> {
 impl<R: RbRef, const P: bool, const C: bool> Frozen<R, P, C> {
     /// Commit changes to the ring buffer.
     pub fn commit(&self) {
+        let rb = self.rb();
+        #[cfg(feature = "metrics")]
+        // Indices are advanced rather than set, so that the ring buffer can account for items passed.
         unsafe {
+            let modulus = 2 * rb.capacity().get();
             if P {
-                self.rb().set_write_index(self.write.get());
+                rb.advance_write_index((modulus + self.write.get() - rb.write_index()) % modulus);
             }
             if C {
-                self.rb().set_read_index(self.read.get());
+                rb.advance_read_index((modulus + self.read.get() - rb.read_index()) % modulus);
+            }
+        }
+        #[cfg(not(feature = "metrics"))]
+        unsafe {
+            if P {
+                rb.set_write_index(self.write.get());
+            }
+            if C {
+                rb.set_read_index(self.read.get());
             }
         }
     }
@@ -165,9 +180,11 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Frozen<R, P, C> {
         self.write.get()
     }
 
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
+    #[inline]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices_mut(start, end)
     }
@@ -180,6 +197,21 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Frozen<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.rb().write_is_held()
     }
+    #[inline]
+    fn is_mirrored(&self) -> bool {
+        self.rb().is_mirrored()
+    }
+
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_written(&self) -> u64 {
+        self.rb().total_written()
+    }
+    #[cfg(feature = "metrics")]
+    #[inline]
+    fn total_read(&self) -> u64 {
+        self.rb().total_read()
+    }
 }
 
 impl<R: RbRef> Producer for FrozenProd<R> {
@@ -187,6 +219,11 @@ impl<R: RbRef> Producer for FrozenProd<R> {
     unsafe fn set_write_index(&self, value: usize) {
         self.write.set(value);
     }
+
+    #[inline]
+    fn flush(&mut self) {
+        self.sync();
+    }
 }
 
 impl<R: RbRef> Consumer for FrozenCons<R> {
@@ -194,6 +231,11 @@ impl<R: RbRef> Consumer for FrozenCons<R> {
     unsafe fn set_read_index(&self, value: usize) {
         self.read.set(value);
     }
+
+    #[inline]
+    fn flush(&mut self) {
+        self.sync();
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> Drop for Frozen<R, P, C> {
@@ -203,5 +245,12 @@ impl<R: RbRef, const P: bool, const C: bool> Drop for Frozen<R, P, C> {
     }
 }
 
+impl_observer_traits!([R: RbRef, const P: bool, const C: bool] Frozen<R, P, C>);
 impl_producer_traits!(FrozenProd<R: RbRef>);
 impl_consumer_traits!(FrozenCons<R: RbRef>);
+
+impl<R: RbRef> core::fmt::Debug for FrozenProd<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::traits::observer::fmt_debug_counts(self, f)
+    }
+}
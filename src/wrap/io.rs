@@ -0,0 +1,148 @@
+//! Blocking [`std::io`] adapters.
+
+use crate::traits::{Based, Consumer, Producer};
+use std::{io, thread};
+
+/// Producer wrapper which [`io::Write`] implementation waits for vacant space instead of returning [`io::ErrorKind::WouldBlock`].
+///
+/// Waiting is done by yielding current thread, so `write_all` can be used directly.
+/// If the consumer is dropped then `write` returns `Ok(0)`.
+///
+/// Created by [`Producer::into_blocking_writer`].
+pub struct BlockingWriter<P: Producer<Item = u8>> {
+    base: P,
+}
+
+impl<P: Producer<Item = u8>> BlockingWriter<P> {
+    /// Wrap producer.
+    pub fn new(base: P) -> Self {
+        Self { base }
+    }
+
+    /// Get the underlying producer.
+    pub fn into_inner(self) -> P {
+        self.base
+    }
+}
+
+impl<P: Producer<Item = u8>> Based for BlockingWriter<P> {
+    type Base = P;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<P: Producer<Item = u8>> io::Write for BlockingWriter<P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            let n = self.base.push_slice(buf);
+            if n > 0 {
+                return Ok(n);
+            }
+            if !self.base.read_is_held() {
+                return Ok(0);
+            }
+            thread::yield_now();
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.base.flush();
+        Ok(())
+    }
+}
+
+/// Consumer wrapper implementing [`io::Read`].
+///
+/// Provides a concrete reader type for generic bounds and trait objects.
+/// Returns [`io::ErrorKind::WouldBlock`] if the ring buffer is empty.
+///
+/// Created by [`Consumer::into_reader`].
+pub struct RbReader<C: Consumer<Item = u8>> {
+    base: C,
+}
+
+impl<C: Consumer<Item = u8>> RbReader<C> {
+    /// Wrap consumer.
+    pub fn new(base: C) -> Self {
+        Self { base }
+    }
+
+    /// Get the underlying consumer.
+    pub fn into_inner(self) -> C {
+        self.base
+    }
+}
+
+impl<C: Consumer<Item = u8>> Based for RbReader<C> {
+    type Base = C;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<C: Consumer<Item = u8>> io::Read for RbReader<C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.base.pop_slice(buf);
+        if n == 0 && !buf.is_empty() {
+            Err(io::ErrorKind::WouldBlock.into())
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+/// Producer wrapper implementing [`io::Write`].
+///
+/// Provides a concrete writer type for generic bounds and trait objects.
+/// Returns [`io::ErrorKind::WouldBlock`] if the ring buffer is full.
+///
+/// Created by [`Producer::into_writer`].
+pub struct RbWriter<P: Producer<Item = u8>> {
+    base: P,
+}
+
+impl<P: Producer<Item = u8>> RbWriter<P> {
+    /// Wrap producer.
+    pub fn new(base: P) -> Self {
+        Self { base }
+    }
+
+    /// Get the underlying producer.
+    pub fn into_inner(self) -> P {
+        self.base
+    }
+}
+
+impl<P: Producer<Item = u8>> Based for RbWriter<P> {
+    type Base = P;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<P: Producer<Item = u8>> io::Write for RbWriter<P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.base.push_slice(buf);
+        if n == 0 && !buf.is_empty() {
+            Err(io::ErrorKind::WouldBlock.into())
+        } else {
+            Ok(n)
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.base.flush();
+        Ok(())
+    }
+}
@@ -1,9 +1,25 @@
 pub mod caching;
+pub mod counted;
 pub mod direct;
+#[cfg(feature = "alloc")]
+pub mod double;
 pub mod frozen;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod rate;
+pub mod sampled;
 mod traits;
 
 pub use caching::{CachingCons, CachingProd};
+pub use counted::Counted;
+#[cfg(feature = "alloc")]
+pub use direct::WeakObs;
 pub use direct::{Cons, Obs, Prod};
+#[cfg(feature = "alloc")]
+pub use double::{DoubleBuffered, DoubleBufferedCons, DoubleBufferedProd};
 pub use frozen::{FrozenCons, FrozenProd};
+#[cfg(feature = "std")]
+pub use io::{BlockingWriter, RbReader, RbWriter};
+pub use rate::RateMetered;
+pub use sampled::Sampled;
 pub use traits::*;
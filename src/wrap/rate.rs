@@ -0,0 +1,60 @@
+//! Rate metering wrapper.
+//!
+//! Counts items pushed between successive samples without external bookkeeping.
+
+use crate::traits::{observer::DelegateObserver, producer::DelegateProducer, Based, Observer, Producer};
+
+/// Wrapper that remembers write position at the last sample and reports how many items were pushed since.
+///
+/// Position is tracked via write index which is modulo `2 * capacity`,
+/// so the ring buffer must be sampled before `2 * capacity` items are pushed.
+pub struct RateMetered<B: Observer> {
+    base: B,
+    last: usize,
+}
+
+impl<B: Observer> RateMetered<B> {
+    /// Wrap `base` starting metering from its current write position.
+    pub fn new(base: B) -> Self {
+        let last = base.write_index();
+        Self { base, last }
+    }
+
+    /// Number of items pushed since the previous call (or since creation).
+    ///
+    /// Current write position is remembered for the next call.
+    pub fn pushed_since_tick(&mut self) -> usize {
+        let count = self.base.items_pushed_since(self.last);
+        self.last = (self.last + count) % (2 * self.base.capacity().get());
+        count
+    }
+
+    /// Get the underlying wrapper.
+    pub fn into_inner(self) -> B {
+        self.base
+    }
+}
+
+impl<B: Observer> Based for RateMetered<B> {
+    type Base = B;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<B: Observer> DelegateObserver for RateMetered<B> {}
+impl<B: Producer> DelegateProducer for RateMetered<B> {}
+
+impl<B: Observer> AsRef<Self> for RateMetered<B> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<B: Observer> AsMut<Self> for RateMetered<B> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
@@ -0,0 +1,91 @@
+//! Occupancy sampling wrapper.
+//!
+//! Records ring buffer occupancy on each push and pop. Useful for tuning ring buffer capacity.
+
+use crate::traits::{observer::DelegateObserver, Based, Consumer, Observer, Producer};
+use core::cell::Cell;
+
+/// Wrapper that samples occupancy of the ring buffer into a histogram of `N` buckets.
+///
+/// Sample is taken each time read or write index is changed through this wrapper.
+///
+/// Bucket `i` counts samples with occupied length in range `[i * capacity / N, (i + 1) * capacity / N)`.
+/// The last bucket also counts samples when the ring buffer is full.
+pub struct Sampled<B: Observer, const N: usize = 8> {
+    base: B,
+    histogram: [Cell<u64>; N],
+}
+
+impl<B: Observer, const N: usize> Sampled<B, N> {
+    /// Wrap `base` into sampler with empty histogram.
+    #[track_caller]
+    pub fn new(base: B) -> Self {
+        assert!(N > 0, "Number of buckets must be greater than zero");
+        Self {
+            base,
+            histogram: core::array::from_fn(|_| Cell::new(0)),
+        }
+    }
+
+    /// Number of samples in each bucket.
+    pub fn histogram(&self) -> [u64; N] {
+        core::array::from_fn(|i| self.histogram[i].get())
+    }
+
+    /// Reset all buckets to zero.
+    pub fn reset_histogram(&mut self) {
+        for bucket in self.histogram.iter() {
+            bucket.set(0);
+        }
+    }
+
+    /// Get the underlying wrapper.
+    pub fn into_inner(self) -> B {
+        self.base
+    }
+
+    fn sample(&self) {
+        let bucket = usize::min(self.base.occupied_len() * N / self.base.capacity(), N - 1);
+        let cell = &self.histogram[bucket];
+        cell.set(cell.get() + 1);
+    }
+}
+
+impl<B: Observer, const N: usize> Based for Sampled<B, N> {
+    type Base = B;
+    fn base(&self) -> &Self::Base {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<B: Observer, const N: usize> DelegateObserver for Sampled<B, N> {}
+
+impl<B: Producer, const N: usize> Producer for Sampled<B, N> {
+    #[inline]
+    unsafe fn set_write_index(&self, value: usize) {
+        self.base.set_write_index(value);
+        self.sample();
+    }
+}
+
+impl<B: Consumer, const N: usize> Consumer for Sampled<B, N> {
+    #[inline]
+    unsafe fn set_read_index(&self, value: usize) {
+        self.base.set_read_index(value);
+        self.sample();
+    }
+}
+
+impl<B: Observer, const N: usize> AsRef<Self> for Sampled<B, N> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<B: Observer, const N: usize> AsMut<Self> for Sampled<B, N> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
@@ -0,0 +1,9 @@
+#[test]
+fn static_capacity() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/static_capacity_valid.rs");
+    t.compile_fail("tests/ui/static_capacity_too_large.rs");
+    t.compile_fail("tests/ui/static_capacity_zero.rs");
+    #[cfg(target_pointer_width = "64")]
+    t.compile_fail("tests/ui/static_capacity_index_type.rs");
+}
@@ -0,0 +1,6 @@
+use core::sync::atomic::AtomicU32;
+use ringbuf::{storage::Array, SharedRb};
+
+fn main() {
+    let _ = SharedRb::<Array<(), { u32::MAX as usize / 2 + 1 }>, AtomicU32>::default();
+}
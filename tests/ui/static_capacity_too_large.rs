@@ -0,0 +1,5 @@
+use ringbuf::{storage::MAX_CAPACITY, StaticRb};
+
+fn main() {
+    let _ = StaticRb::<(), { MAX_CAPACITY + 1 }>::default();
+}
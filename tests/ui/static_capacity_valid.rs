@@ -0,0 +1,15 @@
+use core::sync::atomic::AtomicU32;
+use ringbuf::{
+    storage::{Array, MAX_CAPACITY},
+    traits::*,
+    SharedRb, StaticRb,
+};
+
+fn main() {
+    let rb = StaticRb::<(), MAX_CAPACITY>::default();
+    assert_eq!(rb.capacity().get(), MAX_CAPACITY);
+    let rb = StaticRb::<u8, 4>::from([1, 2, 3, 4]);
+    assert!(rb.is_full());
+    let rb = SharedRb::<Array<(), { u32::MAX as usize / 2 }>, AtomicU32>::default();
+    assert_eq!(rb.capacity().get(), u32::MAX as usize / 2);
+}
@@ -0,0 +1,5 @@
+use ringbuf::StaticRb;
+
+fn main() {
+    let _ = StaticRb::<u8, 0>::default();
+}